@@ -2,7 +2,16 @@ use std::collections::HashMap;
 
 use rayon::prelude::*;
 
-use crate::features::{common::FeatureFunction as _, minimal::MinimalFeatureSet};
+use crate::{
+    features::{
+        common::{FeatureFunction, TimeSeries},
+        minimal::MinimalFeatureSet,
+    },
+    settings::{ColumnSettings, FeatureSettings, SettingsError},
+};
+
+/// Features extracted from one input `HashMap`: column name -> feature name -> value.
+pub type ColumnFeatures = HashMap<String, HashMap<String, f64>>;
 
 /// Extracts features from the given data.
 ///
@@ -13,17 +22,141 @@ use crate::features::{common::FeatureFunction as _, minimal::MinimalFeatureSet};
 /// A vector where each element corresponds to an input `HashMap`. Each result
 /// contains column names mapped to their extracted features, with each feature
 /// map using `feature name -> feature value`.
-pub fn extract_features(
-    data: &[HashMap<String, &[f64]>],
-) -> Vec<HashMap<String, HashMap<String, f64>>> {
+pub fn extract_features(data: &[HashMap<String, &[f64]>]) -> Vec<ColumnFeatures> {
     // apply minimal feature set
+    extract_with(&MinimalFeatureSet::new(), data)
+}
+
+/// Applies `feature_func` to the given data, passing timestamps to
+/// timestamp-aware features.
+///
+/// Each column is a [`TimeSeries`] that may carry timestamps next to its
+/// values, and `feature_func` receives it through
+/// [`FeatureFunction::apply_timed`]. Use [`extract_features_with_settings`] to
+/// select the features by name instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kalax::extract_features_timed;
+/// use kalax::features::common::{TimeSeries, Timestamps};
+/// use kalax::features::trend::{LinearTrendTimewise, TrendAttribute};
+///
+/// let values = vec![1.0, 3.0, 5.0];
+/// let seconds = vec![0.0, 1800.0, 3600.0];
+/// let series = TimeSeries::new(&values).with_timestamps(Timestamps::Seconds(&seconds));
+/// let data = [HashMap::from([("x".to_string(), series)])];
+///
+/// let feature = LinearTrendTimewise::new(vec![TrendAttribute::Slope]);
+/// let results = extract_features_timed(&feature, &data);
+/// assert_eq!(results[0]["x"]["linear_trend_timewise__attr_\"slope\""], 4.0);
+/// ```
+pub fn extract_features_timed(
+    feature_func: &(impl FeatureFunction + Sync),
+    data: &[HashMap<String, TimeSeries<'_>>],
+) -> Vec<ColumnFeatures> {
+    extract_with(feature_func, data)
+}
+
+/// Extracts the features configured in `settings` from the given data.
+///
+/// Columns may be plain `&[f64]` slices or [`TimeSeries`] with timestamps.
+/// Returns an error if the settings name an unknown feature or contain invalid
+/// parameters.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kalax::{extract_features_with_settings, settings::FeatureSettings};
+///
+/// let values = vec![1.0, 2.0, 3.0, 4.0];
+/// let data = [HashMap::from([("x".to_string(), values.as_slice())])];
+/// let results = extract_features_with_settings(&data, &FeatureSettings::efficient()).unwrap();
+/// assert_eq!(results[0]["x"]["mean"], 2.5);
+/// assert_eq!(results[0]["x"]["abs_energy"], 30.0);
+/// ```
+pub fn extract_features_with_settings<'a, S>(
+    data: &[HashMap<String, S>],
+    settings: &FeatureSettings,
+) -> Result<Vec<ColumnFeatures>, SettingsError>
+where
+    S: Into<TimeSeries<'a>> + Copy + Sync,
+{
+    Ok(extract_with(&settings.build()?, data))
+}
+
+/// Extracts features with separate settings for each column name.
+///
+/// Columns listed in `settings` get their own features, all other columns use
+/// the default settings, like tsfresh's `kind_to_fc_parameters`. The settings
+/// of every column are built once and shared across all rows.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kalax::{
+///     extract_features_with_column_settings,
+///     settings::{ColumnSettings, FeatureSettings, params},
+/// };
+///
+/// let mut fft = FeatureSettings::new();
+/// fft.insert("fft_coefficient", vec![params([("coeff", 1.into()), ("attr", "abs".into())])]);
+/// let mut settings = ColumnSettings::new(FeatureSettings::minimal());
+/// settings.insert("vibration", fft);
+///
+/// let vibration = vec![0.0, 1.0, 0.0, -1.0];
+/// let temperature = vec![20.0, 21.0, 22.0];
+/// let data = [HashMap::from([
+///     ("vibration".to_string(), vibration.as_slice()),
+///     ("temperature".to_string(), temperature.as_slice()),
+/// ])];
+/// let results = extract_features_with_column_settings(&data, &settings).unwrap();
+/// assert_eq!(results[0]["vibration"].len(), 1);
+/// assert_eq!(results[0]["temperature"]["mean"], 21.0);
+/// ```
+pub fn extract_features_with_column_settings<'a, S>(
+    data: &[HashMap<String, S>],
+    settings: &ColumnSettings,
+) -> Result<Vec<ColumnFeatures>, SettingsError>
+where
+    S: Into<TimeSeries<'a>> + Copy + Sync,
+{
+    let feature_sets = settings.build()?;
+    Ok(extract_per_column(
+        |col_name| feature_sets.get(col_name),
+        data,
+    ))
+}
 
-    let feature_func = MinimalFeatureSet::new();
+/// Applies `feature_func` to every column of every row in parallel.
+fn extract_with<'a, S>(
+    feature_func: &(impl FeatureFunction + Sync),
+    data: &[HashMap<String, S>],
+) -> Vec<ColumnFeatures>
+where
+    S: Into<TimeSeries<'a>> + Copy + Sync,
+{
+    extract_per_column(|_| feature_func, data)
+}
+
+/// Applies the feature function chosen by `select` for each column name to
+/// every column of every row in parallel.
+fn extract_per_column<'a, 'f, S, F>(
+    select: impl Fn(&str) -> &'f F + Sync,
+    data: &[HashMap<String, S>],
+) -> Vec<ColumnFeatures>
+where
+    S: Into<TimeSeries<'a>> + Copy + Sync,
+    F: FeatureFunction + Sync + 'f,
+{
     data.par_iter()
         .map(|row| {
             row.par_iter()
-                .map(|(col_name, series)| {
-                    let features = feature_func.apply(series);
+                .map(|(col_name, &series)| {
+                    let features = select(col_name).apply_timed(&series.into());
                     let feature_map: HashMap<String, f64> =
                         features.into_iter().map(|f| (f.name, f.value)).collect();
                     (col_name.clone(), feature_map)
@@ -42,7 +175,18 @@ mod tests {
 
     use crate::assert_float_eq;
 
-    use super::extract_features;
+    use super::{
+        extract_features, extract_features_timed, extract_features_with_column_settings,
+        extract_features_with_settings,
+    };
+    use crate::{
+        features::{
+            common::{TimeSeries, Timestamps},
+            minimal::MinimalFeatureSet,
+            trend::{LinearTrendTimewise, TrendAttribute, linear_trend},
+        },
+        settings::{ColumnSettings, FeatureSettings, params},
+    };
 
     #[derive(Deserialize)]
     struct DataRecord {
@@ -94,4 +238,98 @@ mod tests {
             assert_float_eq!(extracted_value, true_value, 1e-6);
         }
     }
+
+    #[test]
+    fn test_timed_extractor() {
+        let values: Vec<f64> = (0..50).map(|i| (0.2 * i as f64).sin()).collect();
+        let seconds: Vec<f64> = (0..50).map(|i| 1800.0 * i as f64).collect();
+        let data = [HashMap::from([(
+            "x".to_string(),
+            TimeSeries::new(&values).with_timestamps(Timestamps::Seconds(&seconds)),
+        )])];
+        let feature = LinearTrendTimewise::new(vec![TrendAttribute::Slope]);
+        let results = extract_features_timed(&feature, &data);
+        assert_float_eq!(
+            results[0]["x"]["linear_trend_timewise__attr_\"slope\""],
+            linear_trend(&values, TrendAttribute::Slope) * 2.0
+        );
+
+        let untimed = extract_features(&[HashMap::from([("x".to_string(), values.as_slice())])]);
+        assert_eq!(
+            extract_features_timed(&MinimalFeatureSet::new(), &data),
+            untimed
+        );
+    }
+
+    #[test]
+    fn test_settings_extractor() {
+        let values: Vec<f64> = (0..50).map(|i| (0.2 * i as f64).sin()).collect();
+        let data = [HashMap::from([("x".to_string(), values.as_slice())])];
+        let minimal = extract_features_with_settings(&data, &FeatureSettings::minimal()).unwrap();
+        assert_eq!(minimal, extract_features(&data));
+
+        let mut settings = FeatureSettings::new();
+        settings.insert("quantile", vec![params([("q", 0.5.into())])]);
+        settings.insert(
+            "linear_trend_timewise",
+            vec![params([("attr", "slope".into())])],
+        );
+        let seconds: Vec<f64> = (0..50).map(|i| 1800.0 * i as f64).collect();
+        let timed = [HashMap::from([(
+            "x".to_string(),
+            TimeSeries::new(&values).with_timestamps(Timestamps::Seconds(&seconds)),
+        )])];
+        let results = extract_features_with_settings(&timed, &settings).unwrap();
+        let expected = linear_trend(&values, TrendAttribute::Slope) * 2.0;
+        assert_float_eq!(
+            results[0]["x"]["linear_trend_timewise__attr_\"slope\""],
+            expected
+        );
+        assert_eq!(results[0]["x"].len(), 2);
+
+        settings.insert("no_such_feature", vec![params([])]);
+        assert!(extract_features_with_settings(&data, &settings).is_err());
+    }
+
+    #[test]
+    fn test_column_settings_extractor() {
+        let vibration: Vec<f64> = (0..64).map(|i| (0.7 * i as f64).sin()).collect();
+        let temperature: Vec<f64> = (0..64).map(|i| 20.0 + 0.1 * i as f64).collect();
+        let data = [
+            HashMap::from([
+                ("vibration".to_string(), vibration.as_slice()),
+                ("temperature".to_string(), temperature.as_slice()),
+            ]),
+            HashMap::from([("vibration".to_string(), temperature.as_slice())]),
+        ];
+
+        let mut spectral = FeatureSettings::new();
+        spectral.insert(
+            "fft_aggregated",
+            vec![params([("aggtype", "centroid".into())])],
+        );
+        spectral.insert("fourier_entropy", vec![params([("bins", 10.into())])]);
+        let mut settings = ColumnSettings::new(FeatureSettings::minimal());
+        settings.insert("vibration", spectral.clone());
+
+        let results = extract_features_with_column_settings(&data, &settings).unwrap();
+        let spectral_only = extract_features_with_settings(&data, &spectral).unwrap();
+        let minimal = extract_features(&data);
+        assert_eq!(results[0]["vibration"], spectral_only[0]["vibration"]);
+        assert_eq!(results[1]["vibration"], spectral_only[1]["vibration"]);
+        assert_eq!(results[0]["temperature"], minimal[0]["temperature"]);
+
+        let uniform = ColumnSettings::new(FeatureSettings::minimal());
+        assert_eq!(
+            extract_features_with_column_settings(&data, &uniform).unwrap(),
+            minimal
+        );
+
+        settings.insert("temperature", {
+            let mut invalid = FeatureSettings::new();
+            invalid.insert("quantile", vec![params([])]);
+            invalid
+        });
+        assert!(extract_features_with_column_settings(&data, &settings).is_err());
+    }
 }
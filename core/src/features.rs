@@ -1,2 +1,17 @@
+pub mod autocorrelation;
+pub mod change;
 pub mod common;
+pub mod counting;
+pub mod distribution;
+pub mod energy;
+pub mod entropy;
+pub mod extrema;
+pub mod langevin;
+pub mod matrix_profile;
 pub mod minimal;
+pub mod nonlinearity;
+pub mod recurring;
+pub mod spectral;
+pub mod stationarity;
+pub mod trend;
+pub mod wavelet;
@@ -0,0 +1,15 @@
+//! Autocorrelation feature extraction module.
+//!
+//! Provides features based on the (partial) autocorrelation function and
+//! fitted autoregressive models.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    ArEstimator, acf, agg_autocorrelation, ar_coefficient, autocorrelation, autocovariance, fit_ar,
+    pacf, partial_autocorrelation,
+};
+pub use oop::{AggAutocorrelation, ArCoefficient, Autocorrelation, PartialAutocorrelation};
@@ -0,0 +1,282 @@
+//! Functional API for autocorrelation feature extraction.
+
+use rustfft::{FftPlanner, num_complex::Complex};
+
+use crate::features::{
+    common::Aggregation,
+    minimal::{mean, variance},
+};
+use crate::math::least_squares;
+
+/// Series longer than this use the FFT to compute the autocovariance, like tsfresh.
+const FFT_THRESHOLD: usize = 1250;
+
+/// Calculates the autocovariance of a time series for lags `0..=max_lag`.
+///
+/// If `adjusted` is true, the sum for lag `k` is divided by `n - k` instead of
+/// `n`, matching statsmodels' `acovf`. Lags beyond the series length are
+/// omitted. Series longer than 1250 values are handled with an FFT.
+pub fn autocovariance(series: &[f64], max_lag: usize, adjusted: bool) -> Vec<f64> {
+    let n = series.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let m = mean(series);
+    let demeaned: Vec<f64> = series.iter().map(|&x| x - m).collect();
+    let lags = max_lag.min(n - 1) + 1;
+    let sums = if n > FFT_THRESHOLD {
+        lagged_products_fft(&demeaned, lags)
+    } else {
+        (0..lags)
+            .map(|k| {
+                demeaned[..n - k]
+                    .iter()
+                    .zip(&demeaned[k..])
+                    .map(|(a, b)| a * b)
+                    .sum()
+            })
+            .collect()
+    };
+    sums.into_iter()
+        .enumerate()
+        .map(|(k, sum)| {
+            if adjusted {
+                sum / (n - k) as f64
+            } else {
+                sum / n as f64
+            }
+        })
+        .collect()
+}
+
+/// Calculates `sum(x[t] * x[t + k])` for the first `lags` lags via the
+/// Wiener-Khinchin theorem.
+fn lagged_products_fft(values: &[f64], lags: usize) -> Vec<f64> {
+    let size = (2 * values.len() - 1).next_power_of_two();
+    let mut buffer: Vec<Complex<f64>> = values.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    buffer.resize(size, Complex::new(0.0, 0.0));
+    let mut planner = FftPlanner::new();
+    planner.plan_fft_forward(size).process(&mut buffer);
+    for c in buffer.iter_mut() {
+        *c = Complex::new(c.norm_sqr(), 0.0);
+    }
+    planner.plan_fft_inverse(size).process(&mut buffer);
+    buffer[..lags].iter().map(|c| c.re / size as f64).collect()
+}
+
+/// Calculates the autocorrelation function of a time series for lags `0..=max_lag`.
+///
+/// Uses the adjusted autocovariance, so `acf[k]` equals [`autocorrelation`] at lag `k`.
+pub fn acf(series: &[f64], max_lag: usize) -> Vec<f64> {
+    let acov = autocovariance(series, max_lag, true);
+    acov.iter().map(|&c| c / acov[0]).collect()
+}
+
+/// Calculates the autocorrelation of a time series at the given lag.
+///
+/// Returns NaN if the lag exceeds the series length or the variance is close to zero.
+pub fn autocorrelation(series: &[f64], lag: usize) -> f64 {
+    let n = series.len();
+    if n < lag {
+        return f64::NAN;
+    }
+    let m = mean(series);
+    let v = variance(series);
+    if v.abs() <= 1e-8 {
+        return f64::NAN;
+    }
+    let sum_product: f64 = series[..n - lag]
+        .iter()
+        .zip(&series[lag..])
+        .map(|(a, b)| (a - m) * (b - m))
+        .sum();
+    sum_product / ((n - lag) as f64 * v)
+}
+
+/// Calculates the autocorrelations for lags `1..=max_lag` as used by
+/// [`agg_autocorrelation`].
+///
+/// Constant and single-value series yield zeros, as in tsfresh.
+pub(crate) fn lagged_autocorrelations(series: &[f64], max_lag: usize) -> Vec<f64> {
+    let n = series.len();
+    if n <= 1 || variance(series).abs() < 1e-10 {
+        return vec![0.0; n];
+    }
+    acf(series, max_lag).split_off(1)
+}
+
+/// Aggregates the autocorrelations for lags `1..=maxlag` with `f_agg`.
+pub fn agg_autocorrelation(series: &[f64], f_agg: Aggregation, maxlag: usize) -> f64 {
+    let autocorrelations = lagged_autocorrelations(series, maxlag);
+    f_agg.apply(&autocorrelations[..maxlag.min(autocorrelations.len())])
+}
+
+/// Runs the Durbin-Levinson recursion on an autocovariance sequence.
+///
+/// Returns the partial autocorrelations for lags `0..=order` (with the lag 0
+/// value set to 1) and the coefficients of the fitted AR(`order`) model.
+/// `autocovariance` must contain at least `order + 1` values.
+pub(crate) fn levinson_durbin(autocovariance: &[f64], order: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut pacf = vec![1.0; order + 1];
+    let mut phi: Vec<f64> = Vec::with_capacity(order);
+    let mut sigma = autocovariance[0];
+    for k in 1..=order {
+        let prediction: f64 = phi
+            .iter()
+            .enumerate()
+            .map(|(j, p)| p * autocovariance[k - 1 - j])
+            .sum();
+        let reflection = (autocovariance[k] - prediction) / sigma;
+        let previous = phi.clone();
+        for j in 0..phi.len() {
+            phi[j] = previous[j] - reflection * previous[k - 2 - j];
+        }
+        phi.push(reflection);
+        sigma *= 1.0 - reflection * reflection;
+        pacf[k] = reflection;
+    }
+    (pacf, phi)
+}
+
+/// Calculates the partial autocorrelation function for lags `0..=max_lag`
+/// with the Durbin-Levinson recursion on the adjusted autocovariance.
+///
+/// This matches statsmodels' `pacf(method="ld")`. `max_lag` must be smaller
+/// than the series length.
+pub fn pacf(series: &[f64], max_lag: usize) -> Vec<f64> {
+    let acov = autocovariance(series, max_lag, true);
+    levinson_durbin(&acov, max_lag).0
+}
+
+/// Calculates the partial autocorrelation of a time series at the given lag.
+///
+/// Like statsmodels, lags are limited to half the series length; larger lags
+/// and series with fewer than 2 values yield NaN.
+pub fn partial_autocorrelation(series: &[f64], lag: usize) -> f64 {
+    partial_autocorrelations(series, &[lag])[0]
+}
+
+/// Calculates the partial autocorrelations at several lags from a single recursion.
+pub(crate) fn partial_autocorrelations(series: &[f64], lags: &[usize]) -> Vec<f64> {
+    let n = series.len();
+    let max_demanded_lag = lags.iter().copied().max().unwrap_or(0);
+    let max_lag = if n <= 1 {
+        0
+    } else {
+        max_demanded_lag.min((n / 2).saturating_sub(1))
+    };
+    let coefficients = if max_lag > 0 {
+        pacf(series, max_lag)
+    } else {
+        Vec::new()
+    };
+    lags.iter()
+        .map(|&lag| coefficients.get(lag).copied().unwrap_or(f64::NAN))
+        .collect()
+}
+
+/// Method used to estimate the coefficients of an autoregressive model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ArEstimator {
+    /// Conditional least squares with an intercept, as in statsmodels'
+    /// `AutoReg(trend="c")` used by tsfresh.
+    #[default]
+    Ols,
+    /// Yule-Walker equations on the biased autocovariance, which always
+    /// yields a stationary model.
+    YuleWalker,
+    /// Burg's method, minimizing the forward and backward prediction errors.
+    Burg,
+}
+
+impl ArEstimator {
+    /// Returns the name of the estimator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArEstimator::Ols => "ols",
+            ArEstimator::YuleWalker => "yule_walker",
+            ArEstimator::Burg => "burg",
+        }
+    }
+}
+
+/// Fits an AR(`k`) model `x[t] = c + φ₁ x[t-1] + … + φₖ x[t-k] + ε[t]`.
+///
+/// Returns `[c, φ₁, …, φₖ]`. The Yule-Walker and Burg estimators work on the
+/// demeaned series, so their intercept is `mean * (1 - Σφ)`.
+///
+/// Returns `None` if the series is too short for the model or the fit is
+/// singular, e.g. for a constant series.
+pub fn fit_ar(series: &[f64], k: usize, estimator: ArEstimator) -> Option<Vec<f64>> {
+    let n = series.len();
+    if n <= k {
+        return None;
+    }
+    let parameters = match estimator {
+        ArEstimator::Ols => {
+            let mut columns = vec![vec![1.0; n - k]];
+            columns.extend((1..=k).map(|lag| series[k - lag..n - lag].to_vec()));
+            least_squares(&columns, &series[k..])?.coefficients
+        }
+        ArEstimator::YuleWalker | ArEstimator::Burg => {
+            let phi = if estimator == ArEstimator::Burg {
+                burg(series, k)?
+            } else {
+                let acov = autocovariance(series, k, false);
+                if acov[0] == 0.0 {
+                    return None;
+                }
+                levinson_durbin(&acov, k).1
+            };
+            let intercept = mean(series) * (1.0 - phi.iter().sum::<f64>());
+            std::iter::once(intercept).chain(phi).collect()
+        }
+    };
+    parameters
+        .iter()
+        .all(|p| p.is_finite())
+        .then_some(parameters)
+}
+
+/// Estimates AR(`order`) coefficients with Burg's recursion on the demeaned series.
+fn burg(series: &[f64], order: usize) -> Option<Vec<f64>> {
+    let m = mean(series);
+    let mut forward: Vec<f64> = series.iter().map(|&x| x - m).collect();
+    let mut backward = forward.clone();
+    let mut phi: Vec<f64> = Vec::with_capacity(order);
+    for k in 1..=order {
+        let (numerator, denominator) = (k..series.len()).fold((0.0, 0.0), |(num, den), t| {
+            (
+                num + 2.0 * forward[t] * backward[t - 1],
+                den + forward[t].powi(2) + backward[t - 1].powi(2),
+            )
+        });
+        if denominator == 0.0 {
+            return None;
+        }
+        let reflection = numerator / denominator;
+        // Walk backwards so `backward[t - 1]` still holds the previous order's error.
+        for t in (k..series.len()).rev() {
+            let f = forward[t];
+            forward[t] = f - reflection * backward[t - 1];
+            backward[t] = backward[t - 1] - reflection * f;
+        }
+        let previous = phi.clone();
+        for j in 0..phi.len() {
+            phi[j] = previous[j] - reflection * previous[k - 2 - j];
+        }
+        phi.push(reflection);
+    }
+    Some(phi)
+}
+
+/// Returns coefficient `coeff` of an AR(`k`) model fitted with `estimator`.
+///
+/// Coefficient 0 is the intercept and coefficient `i` the weight of lag `i`.
+/// Returns NaN if `coeff > k` or the model cannot be fitted.
+pub fn ar_coefficient(series: &[f64], k: usize, coeff: usize, estimator: ArEstimator) -> f64 {
+    if coeff > k {
+        return f64::NAN;
+    }
+    fit_ar(series, k, estimator).map_or(f64::NAN, |parameters| parameters[coeff])
+}
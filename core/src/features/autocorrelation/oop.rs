@@ -0,0 +1,295 @@
+//! OOP API for autocorrelation feature extraction.
+
+use std::collections::HashMap;
+
+use crate::features::common::{
+    Aggregation, FeatureFunction, FeatureFunctionReturn, FormatParam, format_feature_name,
+};
+
+use super::functional::{
+    ArEstimator, autocorrelation, fit_ar, lagged_autocorrelations, partial_autocorrelations,
+};
+
+/// Feature function that calculates the autocorrelation at a given lag.
+pub struct Autocorrelation {
+    pub lag: usize,
+}
+
+impl Autocorrelation {
+    pub fn new(lag: usize) -> Self {
+        Self { lag }
+    }
+}
+
+impl FeatureFunction for Autocorrelation {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("autocorrelation", &[("lag", &self.lag)]),
+            value: autocorrelation(series, self.lag),
+        }]
+    }
+}
+
+/// Feature function that aggregates the autocorrelations over lags `1..=maxlag`.
+///
+/// Each parameter is an `(f_agg, maxlag)` pair. The autocorrelation function is
+/// computed once per series up to the largest requested `maxlag`.
+pub struct AggAutocorrelation {
+    pub params: Vec<(Aggregation, usize)>,
+}
+
+impl AggAutocorrelation {
+    pub fn new(params: Vec<(Aggregation, usize)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for AggAutocorrelation {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let max_maxlag = self.params.iter().map(|&(_, maxlag)| maxlag).max();
+        let autocorrelations = lagged_autocorrelations(series, max_maxlag.unwrap_or(0));
+        self.params
+            .iter()
+            .map(|(f_agg, maxlag)| FeatureFunctionReturn {
+                name: format_feature_name(
+                    "agg_autocorrelation",
+                    &[("f_agg", f_agg), ("maxlag", maxlag)],
+                ),
+                value: f_agg.apply(&autocorrelations[..(*maxlag).min(autocorrelations.len())]),
+            })
+            .collect()
+    }
+}
+
+/// Feature function that calculates the partial autocorrelation at given lags.
+///
+/// The Durbin-Levinson recursion runs once per series up to the largest lag.
+pub struct PartialAutocorrelation {
+    pub lags: Vec<usize>,
+}
+
+impl PartialAutocorrelation {
+    pub fn new(lags: Vec<usize>) -> Self {
+        Self { lags }
+    }
+}
+
+impl FeatureFunction for PartialAutocorrelation {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        partial_autocorrelations(series, &self.lags)
+            .into_iter()
+            .zip(&self.lags)
+            .map(|(value, lag)| FeatureFunctionReturn {
+                name: format_feature_name("partial_autocorrelation", &[("lag", lag)]),
+                value,
+            })
+            .collect()
+    }
+}
+
+/// Feature function that returns coefficients of fitted AR(`k`) models.
+///
+/// Each parameter is a `(coeff, k)` pair. Every distinct `k` is fitted once per
+/// series. Estimators other than the default least squares fit add a `method`
+/// parameter to the feature names.
+pub struct ArCoefficient {
+    pub params: Vec<(usize, usize)>,
+    pub estimator: ArEstimator,
+}
+
+impl ArCoefficient {
+    pub fn new(params: Vec<(usize, usize)>, estimator: ArEstimator) -> Self {
+        Self { params, estimator }
+    }
+
+    fn feature_name(&self, coeff: usize, k: usize) -> String {
+        let method = self.estimator.as_str();
+        let mut params: Vec<(&str, &dyn FormatParam)> = vec![("coeff", &coeff), ("k", &k)];
+        if self.estimator != ArEstimator::default() {
+            params.push(("method", &method));
+        }
+        format_feature_name("ar_coefficient", &params)
+    }
+}
+
+impl FeatureFunction for ArCoefficient {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut fits = HashMap::new();
+        self.params
+            .iter()
+            .map(|&(coeff, k)| {
+                let parameters = fits
+                    .entry(k)
+                    .or_insert_with(|| fit_ar(series, k, self.estimator));
+                FeatureFunctionReturn {
+                    name: self.feature_name(coeff, k),
+                    value: parameters
+                        .as_ref()
+                        .and_then(|parameters| parameters.get(coeff))
+                        .copied()
+                        .unwrap_or(f64::NAN),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_float_eq, features::autocorrelation::autocovariance, test_utils::synthetic_series,
+    };
+
+    #[test]
+    fn test_autocorrelation() {
+        let series = synthetic_series(30);
+        let result = Autocorrelation::new(1).apply(&series);
+        assert_eq!(result[0].name, "autocorrelation__lag_1");
+        assert_float_eq!(result[0].value, 0.8186472236863453);
+
+        let result = Autocorrelation::new(3).apply(&series);
+        assert_float_eq!(result[0].value, 0.6079832081306535);
+
+        let result = Autocorrelation::new(29).apply(&series);
+        assert_float_eq!(result[0].value, 0.31999880965835026);
+
+        let result = Autocorrelation::new(31).apply(&series);
+        assert!(result[0].value.is_nan());
+
+        let constant = [1.0, 1.0, 1.0];
+        let result = Autocorrelation::new(1).apply(&constant);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_agg_autocorrelation() {
+        let series = synthetic_series(30);
+        let result = AggAutocorrelation::new(vec![
+            (Aggregation::Mean, 10),
+            (Aggregation::Median, 10),
+            (Aggregation::Var, 10),
+            (Aggregation::Std, 10),
+            (Aggregation::Mean, 40),
+        ])
+        .apply(&series);
+        assert_eq!(
+            result[0].name,
+            "agg_autocorrelation__f_agg_\"mean\"__maxlag_10"
+        );
+        assert_float_eq!(result[0].value, 0.02112216887059788);
+        assert_float_eq!(result[1].value, 0.01084097265105742);
+        assert_float_eq!(result[2].value, 0.3507489316119647);
+        assert_float_eq!(result[3].value, 0.5922406028059581);
+        assert_float_eq!(result[4].value, 0.02736486131969309);
+
+        let constant = [1.0, 1.0, 1.0];
+        let result = AggAutocorrelation::new(vec![(Aggregation::Mean, 2)]).apply(&constant);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_partial_autocorrelation() {
+        let series = synthetic_series(30);
+        let result = PartialAutocorrelation::new(vec![0, 1, 2, 5, 14, 15]).apply(&series);
+        assert_eq!(result[1].name, "partial_autocorrelation__lag_1");
+        assert_float_eq!(result[0].value, 1.0);
+        assert_float_eq!(result[1].value, 0.8186472236863453);
+        assert_float_eq!(result[2].value, 0.24523998404155462);
+        assert_float_eq!(result[3].value, -0.5477229334932615);
+        assert_float_eq!(result[4].value, -0.5488578875941222);
+        assert!(result[5].value.is_nan());
+
+        let single = [1.0];
+        let result = PartialAutocorrelation::new(vec![1]).apply(&single);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_autocovariance_fft_matches_direct() {
+        let series: Vec<f64> = (0..2000).map(|i| (0.01 * (i * i) as f64).sin()).collect();
+        let acov = autocovariance(&series, 50, true);
+        let m = series.iter().sum::<f64>() / series.len() as f64;
+        for (k, &value) in acov.iter().enumerate() {
+            let direct: f64 = (0..series.len() - k)
+                .map(|t| (series[t] - m) * (series[t + k] - m))
+                .sum::<f64>()
+                / (series.len() - k) as f64;
+            assert_float_eq!(value, direct, 1e-9);
+        }
+    }
+
+    fn ar_series() -> Vec<f64> {
+        let mut state = 0.0;
+        (0..40)
+            .map(|i| {
+                let t = i as f64;
+                state = 0.5 * state + (0.9 * t).sin() + 0.4 * (0.23 * t * t).cos() + 0.1 * t;
+                state
+            })
+            .collect()
+    }
+
+    fn all_coefficients() -> Vec<(usize, usize)> {
+        vec![(0, 1), (1, 1), (2, 1), (0, 3), (1, 3), (2, 3), (3, 3)]
+    }
+
+    #[test]
+    fn test_ar_coefficient_ols() {
+        let series = ar_series();
+        let result = ArCoefficient::new(all_coefficients(), ArEstimator::Ols).apply(&series);
+        assert_eq!(result[0].name, "ar_coefficient__coeff_0__k_1");
+        assert_float_eq!(result[0].value, 0.4014480701436786, 1e-10);
+        assert_float_eq!(result[1].value, 0.9418481588704151, 1e-10);
+        assert!(result[2].value.is_nan());
+        assert_float_eq!(result[3].value, 0.246480278200075, 1e-10);
+        assert_float_eq!(result[4].value, 1.797446175657948, 1e-10);
+        assert_float_eq!(result[5].value, -1.432680067881055, 1e-10);
+        assert_float_eq!(result[6].value, 0.6020808866776148, 1e-10);
+    }
+
+    #[test]
+    fn test_ar_coefficient_yule_walker() {
+        let series = ar_series();
+        let result = ArCoefficient::new(all_coefficients(), ArEstimator::YuleWalker).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "ar_coefficient__coeff_0__k_1__method_\"yule_walker\""
+        );
+        assert_float_eq!(result[0].value, 0.44281905497024293, 1e-10);
+        assert_float_eq!(result[1].value, 0.884768651818598, 1e-10);
+        assert_float_eq!(result[3].value, 0.5148315572706138, 1e-10);
+        assert_float_eq!(result[4].value, 1.243917986529395, 1e-10);
+        assert_float_eq!(result[5].value, -0.5171471013765325, 1e-10);
+        assert_float_eq!(result[6].value, 0.13925851497173222, 1e-10);
+    }
+
+    #[test]
+    fn test_ar_coefficient_burg() {
+        let series = ar_series();
+        let result = ArCoefficient::new(all_coefficients(), ArEstimator::Burg).apply(&series);
+        assert_eq!(
+            result[6].name,
+            "ar_coefficient__coeff_3__k_3__method_\"burg\""
+        );
+        assert_float_eq!(result[0].value, 0.25021773400104474, 1e-10);
+        assert_float_eq!(result[1].value, 0.9348877910645165, 1e-10);
+        assert_float_eq!(result[3].value, 0.1752985399133054, 1e-10);
+        assert_float_eq!(result[4].value, 1.7856880327302767, 1e-10);
+        assert_float_eq!(result[5].value, -1.3854625983275595, 1e-10);
+        assert_float_eq!(result[6].value, 0.5541579940841195, 1e-10);
+    }
+
+    #[test]
+    fn test_ar_coefficient_unfittable() {
+        for estimator in [ArEstimator::Ols, ArEstimator::YuleWalker, ArEstimator::Burg] {
+            let constant = [3.0; 10];
+            let result = ArCoefficient::new(vec![(1, 2)], estimator).apply(&constant);
+            assert!(result[0].value.is_nan());
+
+            let short = [1.0, 2.0, 4.0];
+            let result = ArCoefficient::new(vec![(1, 3)], estimator).apply(&short);
+            assert!(result[0].value.is_nan());
+        }
+    }
+}
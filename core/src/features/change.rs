@@ -0,0 +1,15 @@
+//! Change feature extraction module.
+//!
+//! Provides features describing the differences between consecutive values.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    absolute_sum_of_changes, cid_ce, mean_abs_change, mean_change, mean_second_derivative_central,
+};
+pub use oop::{
+    AbsoluteSumOfChanges, CidCe, MeanAbsChange, MeanChange, MeanSecondDerivativeCentral,
+};
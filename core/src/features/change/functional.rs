@@ -0,0 +1,56 @@
+//! Functional API for change feature extraction.
+
+use crate::features::minimal::{mean, standard_deviation};
+
+/// Calculates the mean over the absolute differences between subsequent values.
+pub fn mean_abs_change(series: &[f64]) -> f64 {
+    absolute_sum_of_changes(series) / series.len().saturating_sub(1) as f64
+}
+
+/// Calculates the mean over the differences between subsequent values.
+///
+/// Returns NaN for series with fewer than 2 values.
+pub fn mean_change(series: &[f64]) -> f64 {
+    let n = series.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    (series[n - 1] - series[0]) / (n - 1) as f64
+}
+
+/// Calculates the mean of a central approximation of the second derivative.
+///
+/// Returns NaN for series with fewer than 3 values.
+pub fn mean_second_derivative_central(series: &[f64]) -> f64 {
+    let n = series.len();
+    if n < 3 {
+        return f64::NAN;
+    }
+    (series[n - 1] - series[n - 2] - series[1] + series[0]) / (2.0 * (n - 2) as f64)
+}
+
+/// Calculates the sum over the absolute differences between subsequent values.
+pub fn absolute_sum_of_changes(series: &[f64]) -> f64 {
+    series.windows(2).map(|w| (w[1] - w[0]).abs()).sum()
+}
+
+/// Calculates the complexity-invariant distance estimate of a time series.
+///
+/// If `normalize` is true, the series is z-transformed first; a constant series
+/// then yields 0.
+pub fn cid_ce(series: &[f64], normalize: bool) -> f64 {
+    let (offset, scale) = if normalize {
+        let s = standard_deviation(series);
+        if s == 0.0 {
+            return 0.0;
+        }
+        (mean(series), s)
+    } else {
+        (0.0, 1.0)
+    };
+    series
+        .windows(2)
+        .map(|w| ((w[1] - offset) / scale - (w[0] - offset) / scale).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
@@ -0,0 +1,177 @@
+//! OOP API for change feature extraction.
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{
+    absolute_sum_of_changes, cid_ce, mean_abs_change, mean_change, mean_second_derivative_central,
+};
+
+/// Feature function that calculates the mean absolute change.
+#[derive(Default)]
+pub struct MeanAbsChange;
+
+impl MeanAbsChange {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for MeanAbsChange {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "mean_abs_change".to_string(),
+            value: mean_abs_change(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the mean change.
+#[derive(Default)]
+pub struct MeanChange;
+
+impl MeanChange {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for MeanChange {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "mean_change".to_string(),
+            value: mean_change(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the mean central second derivative.
+#[derive(Default)]
+pub struct MeanSecondDerivativeCentral;
+
+impl MeanSecondDerivativeCentral {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for MeanSecondDerivativeCentral {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "mean_second_derivative_central".to_string(),
+            value: mean_second_derivative_central(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the absolute sum of changes.
+#[derive(Default)]
+pub struct AbsoluteSumOfChanges;
+
+impl AbsoluteSumOfChanges {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for AbsoluteSumOfChanges {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "absolute_sum_of_changes".to_string(),
+            value: absolute_sum_of_changes(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the complexity-invariant distance estimate.
+pub struct CidCe {
+    pub normalize: bool,
+}
+
+impl CidCe {
+    pub fn new(normalize: bool) -> Self {
+        Self { normalize }
+    }
+}
+
+impl FeatureFunction for CidCe {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("cid_ce", &[("normalize", &self.normalize)]),
+            value: cid_ce(series, self.normalize),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_mean_abs_change() {
+        let series = [1.0, 3.0, 2.0, 6.0, 4.0];
+        let result = MeanAbsChange::new().apply(&series);
+        assert_float_eq!(result[0].value, 2.25);
+
+        let single = [1.0];
+        let result = MeanAbsChange::new().apply(&single);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_mean_change() {
+        let series = [1.0, 3.0, 2.0, 6.0, 4.0];
+        let result = MeanChange::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.75);
+
+        let single = [1.0];
+        let result = MeanChange::new().apply(&single);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_mean_second_derivative_central() {
+        let series = [1.0, 3.0, 2.0, 6.0, 4.0];
+        let result = MeanSecondDerivativeCentral::new().apply(&series);
+        assert_float_eq!(result[0].value, -4.0 / 6.0);
+
+        let quadratic = [0.0, 1.0, 4.0, 9.0, 16.0];
+        let result = MeanSecondDerivativeCentral::new().apply(&quadratic);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let short = [1.0, 2.0];
+        let result = MeanSecondDerivativeCentral::new().apply(&short);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_absolute_sum_of_changes() {
+        let series = [1.0, 3.0, 2.0, 6.0, 4.0];
+        let result = AbsoluteSumOfChanges::new().apply(&series);
+        assert_float_eq!(result[0].value, 9.0);
+
+        let empty: [f64; 0] = [];
+        let result = AbsoluteSumOfChanges::new().apply(&empty);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_cid_ce() {
+        let series = [1.0, 3.0, 2.0, 6.0, 4.0];
+        let result = CidCe::new(false).apply(&series);
+        assert_eq!(result[0].name, "cid_ce__normalize_False");
+        assert_float_eq!(result[0].value, 5.0);
+
+        let result = CidCe::new(true).apply(&series);
+        assert_eq!(result[0].name, "cid_ce__normalize_True");
+        assert_float_eq!(result[0].value, 5.0 / 2.96f64.sqrt());
+
+        let constant = [2.0, 2.0, 2.0];
+        let result = CidCe::new(true).apply(&constant);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+}
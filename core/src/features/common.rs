@@ -1,3 +1,5 @@
+use crate::features::minimal::{maximum, mean, median, minimum, standard_deviation, variance};
+
 /// Represents the result of a feature extraction operation.
 ///
 /// Contains the name of the feature and its computed value.
@@ -81,4 +83,355 @@ pub trait FeatureFunction {
     /// - The function should not modify the input series
     /// - Feature names should be descriptive and consistent across implementations
     fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn>;
+
+    /// Apply the feature extraction function to a time series that may carry timestamps.
+    ///
+    /// The default implementation ignores the timestamps and calls [`apply`](Self::apply).
+    /// Timestamp-aware features override it.
+    fn apply_timed(&self, series: &TimeSeries<'_>) -> Vec<FeatureFunctionReturn> {
+        self.apply(series.values)
+    }
+}
+
+/// Timestamps of the values of a time series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Timestamps<'a> {
+    /// Nanoseconds since the Unix epoch, like `numpy.datetime64[ns]`.
+    Nanoseconds(&'a [i64]),
+    /// Seconds since an arbitrary origin.
+    Seconds(&'a [f64]),
+}
+
+impl Timestamps<'_> {
+    /// Returns the number of timestamps.
+    pub fn len(&self) -> usize {
+        match self {
+            Timestamps::Nanoseconds(t) => t.len(),
+            Timestamps::Seconds(t) => t.len(),
+        }
+    }
+
+    /// Returns `true` if there are no timestamps.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the time elapsed since the first timestamp in hours.
+    pub fn hours_since_start(&self) -> Vec<f64> {
+        const SECONDS_PER_HOUR: f64 = 3600.0;
+        match self {
+            // The difference of two i64 values can overflow i64, but not i128.
+            Timestamps::Nanoseconds(t) => t
+                .iter()
+                .map(|&ns| (i128::from(ns) - i128::from(t[0])) as f64 / 1e9 / SECONDS_PER_HOUR)
+                .collect(),
+            Timestamps::Seconds(t) => t.iter().map(|&s| (s - t[0]) / SECONDS_PER_HOUR).collect(),
+        }
+    }
+}
+
+/// A time series with optional timestamps, the input of [`FeatureFunction::apply_timed`].
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::common::{TimeSeries, Timestamps};
+///
+/// let values = [1.0, 2.0, 4.0];
+/// let seconds = [0.0, 1800.0, 3600.0];
+/// let series = TimeSeries::new(&values).with_timestamps(Timestamps::Seconds(&seconds));
+///
+/// assert_eq!(series.timestamps.unwrap().hours_since_start(), vec![0.0, 0.5, 1.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeSeries<'a> {
+    pub values: &'a [f64],
+    /// Timestamps of `values`, expected to have the same length.
+    pub timestamps: Option<Timestamps<'a>>,
+}
+
+impl<'a> TimeSeries<'a> {
+    /// Creates a time series without timestamps.
+    pub fn new(values: &'a [f64]) -> Self {
+        Self {
+            values,
+            timestamps: None,
+        }
+    }
+
+    /// Attaches timestamps to the time series.
+    pub fn with_timestamps(self, timestamps: Timestamps<'a>) -> Self {
+        Self {
+            timestamps: Some(timestamps),
+            ..self
+        }
+    }
+}
+
+impl<'a> From<&'a [f64]> for TimeSeries<'a> {
+    fn from(values: &'a [f64]) -> Self {
+        Self::new(values)
+    }
+}
+
+/// Formats a parameter value the way tsfresh renders it inside feature names.
+///
+/// Floats use Python's `repr` style (`1.0`, `0.1`, `1e-05`), booleans render as
+/// `True`/`False` and strings are wrapped in double quotes.
+pub trait FormatParam {
+    /// Returns the textual representation of the parameter value.
+    fn format_param(&self) -> String;
+}
+
+impl FormatParam for f64 {
+    fn format_param(&self) -> String {
+        if self.is_nan() {
+            return "nan".to_string();
+        }
+        if self.is_infinite() {
+            return if *self > 0.0 { "inf" } else { "-inf" }.to_string();
+        }
+        let abs = self.abs();
+        if abs != 0.0 && !(1e-4..1e16).contains(&abs) {
+            // Python switches to scientific notation with a signed, two-digit exponent.
+            let formatted = format!("{:e}", self);
+            let (mantissa, exponent) = formatted.split_once('e').unwrap();
+            let (sign, digits) = match exponent.strip_prefix('-') {
+                Some(digits) => ('-', digits),
+                None => ('+', exponent),
+            };
+            return format!("{mantissa}e{sign}{digits:0>2}");
+        }
+        format!("{:?}", self)
+    }
+}
+
+/// A numeric parameter that remembers whether it was given as an integer or a float.
+///
+/// tsfresh renders `0` and `0.0` differently in feature names (`m_0` vs `m_0.0`),
+/// so threshold-like parameters keep the original kind to stay name-compatible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl Number {
+    /// Returns the value as `f64` for use in computations.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Number::Int(v) => v as f64,
+            Number::Float(v) => v,
+        }
+    }
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Number::Int(value.into())
+    }
+}
+
+impl From<i64> for Number {
+    fn from(value: i64) -> Self {
+        Number::Int(value)
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Number::Float(value)
+    }
+}
+
+impl FormatParam for Number {
+    fn format_param(&self) -> String {
+        match self {
+            Number::Int(v) => v.format_param(),
+            Number::Float(v) => v.format_param(),
+        }
+    }
+}
+
+impl FormatParam for usize {
+    fn format_param(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FormatParam for i64 {
+    fn format_param(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FormatParam for bool {
+    fn format_param(&self) -> String {
+        if *self { "True" } else { "False" }.to_string()
+    }
+}
+
+impl FormatParam for Vec<usize> {
+    /// Renders the values as a Python tuple, e.g. `(2, 5, 10, 20)`.
+    fn format_param(&self) -> String {
+        match self.as_slice() {
+            [single] => format!("({single},)"),
+            values => {
+                let items: Vec<String> = values.iter().map(usize::to_string).collect();
+                format!("({})", items.join(", "))
+            }
+        }
+    }
+}
+
+impl FormatParam for &str {
+    fn format_param(&self) -> String {
+        format!("\"{self}\"")
+    }
+}
+
+impl FormatParam for String {
+    fn format_param(&self) -> String {
+        self.as_str().format_param()
+    }
+}
+
+/// Builds a tsfresh-compatible feature name from a base name and its parameters.
+///
+/// Parameters are sorted by key and joined as `base__key_value__key_value`.
+/// The result is canonical: [`parse_feature_name`](crate::settings::parse_feature_name)
+/// recovers the base name and parameters from it.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::common::format_feature_name;
+///
+/// assert_eq!(format_feature_name("quantile", &[("q", &0.1)]), "quantile__q_0.1");
+/// assert_eq!(
+///     format_feature_name("fft_coefficient", &[("coeff", &3usize), ("attr", &"real")]),
+///     "fft_coefficient__attr_\"real\"__coeff_3"
+/// );
+/// ```
+pub fn format_feature_name(base: &str, params: &[(&str, &dyn FormatParam)]) -> String {
+    let mut params = params.to_vec();
+    params.sort_by_key(|(key, _)| *key);
+    let mut name = base.to_string();
+    for (key, value) in params {
+        name.push_str("__");
+        name.push_str(key);
+        name.push('_');
+        name.push_str(&value.format_param());
+    }
+    name
+}
+
+/// Aggregation applied to a vector of intermediate values, named after the
+/// numpy function tsfresh uses for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    Mean,
+    Median,
+    /// Population variance (`numpy.var`).
+    Var,
+    /// Population standard deviation (`numpy.std`).
+    Std,
+    Min,
+    Max,
+}
+
+impl Aggregation {
+    /// Returns the numpy name of the aggregation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Aggregation::Mean => "mean",
+            Aggregation::Median => "median",
+            Aggregation::Var => "var",
+            Aggregation::Std => "std",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+        }
+    }
+
+    /// Aggregates `values`, returning NaN if it is empty.
+    pub fn apply(&self, values: &[f64]) -> f64 {
+        if values.is_empty() {
+            return f64::NAN;
+        }
+        match self {
+            Aggregation::Mean => mean(values),
+            Aggregation::Median => median(values),
+            Aggregation::Var => variance(values),
+            Aggregation::Std => standard_deviation(values),
+            Aggregation::Min => minimum(values),
+            Aggregation::Max => maximum(values),
+        }
+    }
+}
+
+impl FormatParam for Aggregation {
+    fn format_param(&self) -> String {
+        self.as_str().format_param()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_param_float() {
+        assert_eq!(0.0.format_param(), "0.0");
+        assert_eq!(1.0.format_param(), "1.0");
+        assert_eq!(0.1.format_param(), "0.1");
+        assert_eq!((-2.5).format_param(), "-2.5");
+        assert_eq!(0.0001.format_param(), "0.0001");
+        assert_eq!(0.00001.format_param(), "1e-05");
+        assert_eq!(1e16.format_param(), "1e+16");
+        assert_eq!(f64::NAN.format_param(), "nan");
+    }
+
+    #[test]
+    fn test_format_param_number() {
+        assert_eq!(Number::from(0).format_param(), "0");
+        assert_eq!(Number::from(-1).format_param(), "-1");
+        assert_eq!(Number::from(0.0).format_param(), "0.0");
+        assert_eq!(Number::from(-1e12).format_param(), "-1000000000000.0");
+    }
+
+    #[test]
+    fn test_format_param_tuple() {
+        assert_eq!(vec![2, 5, 10, 20].format_param(), "(2, 5, 10, 20)");
+        assert_eq!(vec![2].format_param(), "(2,)");
+        assert_eq!(Vec::<usize>::new().format_param(), "()");
+    }
+
+    #[test]
+    fn test_format_feature_name() {
+        assert_eq!(format_feature_name("mean", &[]), "mean");
+        assert_eq!(
+            format_feature_name(
+                "change_quantiles",
+                &[
+                    ("ql", &0.0),
+                    ("qh", &0.2),
+                    ("isabs", &false),
+                    ("f_agg", &"mean"),
+                ]
+            ),
+            "change_quantiles__f_agg_\"mean\"__isabs_False__qh_0.2__ql_0.0"
+        );
+    }
+
+    #[test]
+    fn test_hours_since_start_far_apart() {
+        let hours = Timestamps::Nanoseconds(&[i64::MIN, 0, i64::MAX]).hours_since_start();
+        let span = 2f64.powi(64) / 1e9 / 3600.0;
+        assert_eq!(hours[0], 0.0);
+        assert_eq!(hours[1], span / 2.0);
+        assert_eq!(hours[2], span);
+
+        let hours = Timestamps::Nanoseconds(&[i64::MAX, i64::MIN]).hours_since_start();
+        assert_eq!(hours[1], -span);
+    }
 }
@@ -0,0 +1,17 @@
+//! Counting feature extraction module.
+//!
+//! Provides features counting peaks, threshold crossings, runs of values
+//! relative to the mean and occurrences of given values or ranges. Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    count_above, count_above_mean, count_below, count_below_mean, longest_strike_above_mean,
+    longest_strike_below_mean, number_crossing_m, number_peaks, range_count, value_count,
+};
+pub use oop::{
+    CountAbove, CountAboveMean, CountBelow, CountBelowMean, LongestStrikeAboveMean,
+    LongestStrikeBelowMean, NumberCrossingM, NumberPeaks, RangeCount, ValueCount,
+};
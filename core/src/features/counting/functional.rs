@@ -0,0 +1,92 @@
+//! Functional API for counting feature extraction.
+
+use crate::features::minimal::mean;
+
+/// Calculates the number of peaks of at least support `n`.
+///
+/// A peak of support `n` is a value that is strictly larger than its `n`
+/// neighbours to the left and to the right. Like tsfresh, returns 0 for
+/// `n == 0`.
+pub fn number_peaks(series: &[f64], n: usize) -> usize {
+    if n == 0 || series.len() <= 2 * n {
+        return 0;
+    }
+    (n..series.len() - n)
+        .filter(|&i| (1..=n).all(|j| series[i] > series[i - j] && series[i] > series[i + j]))
+        .count()
+}
+
+/// Calculates the number of crossings of a time series on `m`.
+///
+/// A crossing happens whenever two consecutive values lie on different sides of
+/// `m`, where values equal to `m` count as lying below it.
+pub fn number_crossing_m(series: &[f64], m: f64) -> usize {
+    series
+        .windows(2)
+        .filter(|w| (w[0] > m) != (w[1] > m))
+        .count()
+}
+
+/// Counts the values that are higher than the mean of a time series.
+pub fn count_above_mean(series: &[f64]) -> usize {
+    let m = mean(series);
+    series.iter().filter(|&&x| x > m).count()
+}
+
+/// Counts the values that are lower than the mean of a time series.
+pub fn count_below_mean(series: &[f64]) -> usize {
+    let m = mean(series);
+    series.iter().filter(|&&x| x < m).count()
+}
+
+/// Calculates the fraction of values that are higher than or equal to `t`.
+pub fn count_above(series: &[f64], t: f64) -> f64 {
+    series.iter().filter(|&&x| x >= t).count() as f64 / series.len() as f64
+}
+
+/// Calculates the fraction of values that are lower than or equal to `t`.
+pub fn count_below(series: &[f64], t: f64) -> f64 {
+    series.iter().filter(|&&x| x <= t).count() as f64 / series.len() as f64
+}
+
+/// Counts the occurrences of `value` in a time series.
+///
+/// A NaN `value` counts the NaN values.
+pub fn value_count(series: &[f64], value: f64) -> usize {
+    if value.is_nan() {
+        return series.iter().filter(|x| x.is_nan()).count();
+    }
+    series.iter().filter(|&&x| x == value).count()
+}
+
+/// Counts the values of a time series within the half-open interval `[min, max)`.
+pub fn range_count(series: &[f64], min: f64, max: f64) -> usize {
+    series.iter().filter(|&&x| x >= min && x < max).count()
+}
+
+/// Returns the length of the longest run of values higher than the mean.
+pub fn longest_strike_above_mean(series: &[f64]) -> usize {
+    let m = mean(series);
+    longest_strike(series, |x| x > m)
+}
+
+/// Returns the length of the longest run of values lower than the mean.
+pub fn longest_strike_below_mean(series: &[f64]) -> usize {
+    let m = mean(series);
+    longest_strike(series, |x| x < m)
+}
+
+/// Returns the length of the longest run of consecutive values satisfying `predicate`.
+fn longest_strike(series: &[f64], predicate: impl Fn(f64) -> bool) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for &x in series {
+        if predicate(x) {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
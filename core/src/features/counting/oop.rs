@@ -0,0 +1,344 @@
+//! OOP API for counting feature extraction.
+
+use crate::features::common::{
+    FeatureFunction, FeatureFunctionReturn, Number, format_feature_name,
+};
+
+use super::functional::{
+    count_above, count_above_mean, count_below, count_below_mean, longest_strike_above_mean,
+    longest_strike_below_mean, number_crossing_m, number_peaks, range_count, value_count,
+};
+
+/// Feature function that counts the peaks of support `n`.
+pub struct NumberPeaks {
+    pub n: usize,
+}
+
+impl NumberPeaks {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+impl FeatureFunction for NumberPeaks {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("number_peaks", &[("n", &self.n)]),
+            value: number_peaks(series, self.n) as f64,
+        }]
+    }
+}
+
+/// Feature function that counts the crossings of the series on `m`.
+pub struct NumberCrossingM {
+    pub m: Number,
+}
+
+impl NumberCrossingM {
+    pub fn new(m: impl Into<Number>) -> Self {
+        Self { m: m.into() }
+    }
+}
+
+impl FeatureFunction for NumberCrossingM {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("number_crossing_m", &[("m", &self.m)]),
+            value: number_crossing_m(series, self.m.as_f64()) as f64,
+        }]
+    }
+}
+
+/// Feature function that counts the values above the mean.
+#[derive(Default)]
+pub struct CountAboveMean;
+
+impl CountAboveMean {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for CountAboveMean {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "count_above_mean".to_string(),
+            value: count_above_mean(series) as f64,
+        }]
+    }
+}
+
+/// Feature function that counts the values below the mean.
+#[derive(Default)]
+pub struct CountBelowMean;
+
+impl CountBelowMean {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for CountBelowMean {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "count_below_mean".to_string(),
+            value: count_below_mean(series) as f64,
+        }]
+    }
+}
+
+/// Feature function that calculates the longest run of values above the mean.
+#[derive(Default)]
+pub struct LongestStrikeAboveMean;
+
+impl LongestStrikeAboveMean {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for LongestStrikeAboveMean {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "longest_strike_above_mean".to_string(),
+            value: longest_strike_above_mean(series) as f64,
+        }]
+    }
+}
+
+/// Feature function that calculates the longest run of values below the mean.
+#[derive(Default)]
+pub struct LongestStrikeBelowMean;
+
+impl LongestStrikeBelowMean {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for LongestStrikeBelowMean {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "longest_strike_below_mean".to_string(),
+            value: longest_strike_below_mean(series) as f64,
+        }]
+    }
+}
+
+/// Feature function that calculates the fraction of values higher than or equal to `t`.
+pub struct CountAbove {
+    pub t: Number,
+}
+
+impl CountAbove {
+    pub fn new(t: impl Into<Number>) -> Self {
+        Self { t: t.into() }
+    }
+}
+
+impl FeatureFunction for CountAbove {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("count_above", &[("t", &self.t)]),
+            value: count_above(series, self.t.as_f64()),
+        }]
+    }
+}
+
+/// Feature function that calculates the fraction of values lower than or equal to `t`.
+pub struct CountBelow {
+    pub t: Number,
+}
+
+impl CountBelow {
+    pub fn new(t: impl Into<Number>) -> Self {
+        Self { t: t.into() }
+    }
+}
+
+impl FeatureFunction for CountBelow {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("count_below", &[("t", &self.t)]),
+            value: count_below(series, self.t.as_f64()),
+        }]
+    }
+}
+
+/// Feature function that counts the occurrences of `value`.
+pub struct ValueCount {
+    pub value: Number,
+}
+
+impl ValueCount {
+    pub fn new(value: impl Into<Number>) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+impl FeatureFunction for ValueCount {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("value_count", &[("value", &self.value)]),
+            value: value_count(series, self.value.as_f64()) as f64,
+        }]
+    }
+}
+
+/// Feature function that counts the values within `[min, max)`.
+pub struct RangeCount {
+    pub min: Number,
+    pub max: Number,
+}
+
+impl RangeCount {
+    pub fn new(min: impl Into<Number>, max: impl Into<Number>) -> Self {
+        Self {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+}
+
+impl FeatureFunction for RangeCount {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("range_count", &[("min", &self.min), ("max", &self.max)]),
+            value: range_count(series, self.min.as_f64(), self.max.as_f64()) as f64,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_number_peaks() {
+        let series = [3.0, 0.0, 0.0, 4.0, 0.0, 0.0, 13.0];
+        let result = NumberPeaks::new(1).apply(&series);
+        assert_eq!(result[0].name, "number_peaks__n_1");
+        assert_float_eq!(result[0].value, 1.0);
+
+        let result = NumberPeaks::new(2).apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let result = NumberPeaks::new(3).apply(&series);
+        assert_eq!(result[0].name, "number_peaks__n_3");
+        assert_float_eq!(result[0].value, 0.0);
+
+        let short = [1.0, 2.0];
+        let result = NumberPeaks::new(1).apply(&short);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let result = NumberPeaks::new(0).apply(&series);
+        assert_eq!(result[0].name, "number_peaks__n_0");
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_number_crossing_m() {
+        let series = [1.0, -1.0, 2.0, 0.0, 3.0];
+        let result = NumberCrossingM::new(0).apply(&series);
+        assert_eq!(result[0].name, "number_crossing_m__m_0");
+        assert_float_eq!(result[0].value, 4.0);
+
+        let result = NumberCrossingM::new(1.5).apply(&series);
+        assert_eq!(result[0].name, "number_crossing_m__m_1.5");
+        assert_float_eq!(result[0].value, 3.0);
+    }
+
+    #[test]
+    fn test_count_above_mean() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = CountAboveMean::new().apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let constant = [2.0, 2.0, 2.0];
+        let result = CountAboveMean::new().apply(&constant);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_count_below_mean() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = CountBelowMean::new().apply(&series);
+        assert_float_eq!(result[0].value, 3.0);
+    }
+
+    #[test]
+    fn test_longest_strike_above_mean() {
+        let series = [1.0, 5.0, 6.0, 1.0, 1.0, 1.0, 7.0];
+        let result = LongestStrikeAboveMean::new().apply(&series);
+        assert_float_eq!(result[0].value, 2.0);
+
+        let empty: [f64; 0] = [];
+        let result = LongestStrikeAboveMean::new().apply(&empty);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_longest_strike_below_mean() {
+        let series = [1.0, 5.0, 6.0, 1.0, 1.0, 1.0, 7.0];
+        let result = LongestStrikeBelowMean::new().apply(&series);
+        assert_float_eq!(result[0].value, 3.0);
+    }
+
+    #[test]
+    fn test_count_above() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = CountAbove::new(3).apply(&series);
+        assert_eq!(result[0].name, "count_above__t_3");
+        assert_float_eq!(result[0].value, 0.6);
+
+        let empty: [f64; 0] = [];
+        let result = CountAbove::new(0).apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_count_below() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = CountBelow::new(3.0).apply(&series);
+        assert_eq!(result[0].name, "count_below__t_3.0");
+        assert_float_eq!(result[0].value, 0.6);
+    }
+
+    #[test]
+    fn test_value_count() {
+        let series = [1.0, 0.0, -1.0, 1.0, f64::NAN, 1.0];
+        let result = ValueCount::new(1).apply(&series);
+        assert_eq!(result[0].name, "value_count__value_1");
+        assert_float_eq!(result[0].value, 3.0);
+
+        let result = ValueCount::new(0.0).apply(&series);
+        assert_eq!(result[0].name, "value_count__value_0.0");
+        assert_float_eq!(result[0].value, 1.0);
+
+        let result = ValueCount::new(f64::NAN).apply(&series);
+        assert_eq!(result[0].name, "value_count__value_nan");
+        assert_float_eq!(result[0].value, 1.0);
+    }
+
+    #[test]
+    fn test_range_count() {
+        let series = [-2.0, -1.0, 0.0, 0.5, 1.0, 3.0];
+        let result = RangeCount::new(-1, 1).apply(&series);
+        assert_eq!(result[0].name, "range_count__max_1__min_-1");
+        assert_float_eq!(result[0].value, 3.0);
+
+        let result = RangeCount::new(0, 1e12).apply(&series);
+        assert_eq!(result[0].name, "range_count__max_1000000000000.0__min_0");
+        assert_float_eq!(result[0].value, 4.0);
+
+        let result = RangeCount::new(1e12, 0).apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+}
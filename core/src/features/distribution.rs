@@ -0,0 +1,22 @@
+//! Distribution feature extraction module.
+//!
+//! Provides features describing the shape and spread of the value distribution,
+//! where its mass lies along the series and how it changes within quantile
+//! corridors.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    benford_correlation, change_quantiles, index_mass_quantile, kurtosis, large_standard_deviation,
+    quantile, ratio_beyond_r_sigma, skewness, symmetry_looking,
+    variance_larger_than_standard_deviation, variation_coefficient,
+};
+pub(crate) use functional::{is_unit_interval, quantile_sorted};
+pub use oop::{
+    BenfordCorrelation, ChangeQuantiles, IndexMassQuantile, Kurtosis, LargeStandardDeviation,
+    Quantile, RatioBeyondRSigma, Skewness, SymmetryLooking, VarianceLargerThanStandardDeviation,
+    VariationCoefficient,
+};
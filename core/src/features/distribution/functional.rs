@@ -0,0 +1,292 @@
+//! Functional API for distribution feature extraction.
+
+use crate::features::{
+    common::Aggregation,
+    minimal::{maximum, mean, median, minimum, standard_deviation, variance},
+};
+
+/// Sets values that are indistinguishable from floating point noise to zero,
+/// mirroring pandas' `_zero_out_fperr`.
+fn zero_out_fperr(x: f64) -> f64 {
+    if x.abs() < 1e-14 { 0.0 } else { x }
+}
+
+/// Calculates the sample skewness of a time series.
+///
+/// Uses the adjusted Fisher-Pearson standardized moment coefficient G1, as
+/// pandas does. Returns NaN for series shorter than 3 and 0 for constant series.
+pub fn skewness(series: &[f64]) -> f64 {
+    let n = series.len() as f64;
+    if series.len() < 3 {
+        return f64::NAN;
+    }
+    let m = mean(series);
+    let m2 = zero_out_fperr(series.iter().map(|&x| (x - m).powi(2)).sum::<f64>());
+    let m3 = zero_out_fperr(series.iter().map(|&x| (x - m).powi(3)).sum::<f64>());
+    if m2 == 0.0 {
+        return 0.0;
+    }
+    (n * (n - 1.0).sqrt() / (n - 2.0)) * (m3 / m2.powf(1.5))
+}
+
+/// Calculates the sample excess kurtosis of a time series.
+///
+/// Uses the adjusted Fisher-Pearson standardized moment coefficient G2, as
+/// pandas does. Returns NaN for series shorter than 4 and 0 for constant series.
+pub fn kurtosis(series: &[f64]) -> f64 {
+    let n = series.len() as f64;
+    if series.len() < 4 {
+        return f64::NAN;
+    }
+    let m = mean(series);
+    let m2 = zero_out_fperr(series.iter().map(|&x| (x - m).powi(2)).sum::<f64>());
+    let m4 = zero_out_fperr(series.iter().map(|&x| (x - m).powi(4)).sum::<f64>());
+    let adj = 3.0 * (n - 1.0).powi(2) / ((n - 2.0) * (n - 3.0));
+    let numerator = n * (n + 1.0) * (n - 1.0) * m4;
+    let denominator = (n - 2.0) * (n - 3.0) * m2.powi(2);
+    if denominator == 0.0 {
+        return 0.0;
+    }
+    numerator / denominator - adj
+}
+
+/// Calculates the variation coefficient (standard deviation / mean) of a time series.
+///
+/// Returns NaN if the mean is zero.
+pub fn variation_coefficient(series: &[f64]) -> f64 {
+    let avg = mean(series);
+    if avg == 0.0 {
+        return f64::NAN;
+    }
+    standard_deviation(series) / avg
+}
+
+/// Calculates the `q` quantile of a time series.
+///
+/// Uses linear interpolation between the closest ranks, matching `numpy.quantile`.
+/// Returns NaN for an empty series, if the series contains NaN values, like
+/// numpy does, and if `q` lies outside `[0, 1]`.
+pub fn quantile(series: &[f64], q: f64) -> f64 {
+    if series.is_empty() || series.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    let mut sorted = series.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    quantile_sorted(&sorted, q)
+}
+
+/// Returns `true` if `q` is a valid quantile, i.e. lies in `[0, 1]`.
+pub(crate) fn is_unit_interval(q: f64) -> bool {
+    (0.0..=1.0).contains(&q)
+}
+
+/// Calculates the `q` quantile of an already sorted, non-empty slice.
+///
+/// Returns NaN if `q` lies outside `[0, 1]`.
+pub(crate) fn quantile_sorted(sorted: &[f64], q: f64) -> f64 {
+    if !is_unit_interval(q) {
+        return f64::NAN;
+    }
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
+
+/// Checks whether the variance of a time series is larger than its standard deviation.
+///
+/// This is equivalent to the variance being larger than 1.
+pub fn variance_larger_than_standard_deviation(series: &[f64]) -> bool {
+    let y = variance(series);
+    y > y.sqrt()
+}
+
+/// Calculates the fraction of values that are more than `r` standard
+/// deviations away from the mean of a time series.
+///
+/// Returns NaN for an empty series.
+pub fn ratio_beyond_r_sigma(series: &[f64], r: f64) -> f64 {
+    let m = mean(series);
+    let limit = r * standard_deviation(series);
+    series.iter().filter(|&&x| (x - m).abs() > limit).count() as f64 / series.len() as f64
+}
+
+/// Checks whether the standard deviation of a time series is larger than `r`
+/// times its range.
+pub fn large_standard_deviation(series: &[f64], r: f64) -> bool {
+    standard_deviation(series) > r * (maximum(series) - minimum(series))
+}
+
+/// Returns the distance between mean and median and the range of a time
+/// series, the two terms compared by [`symmetry_looking`].
+pub(crate) fn symmetry_terms(series: &[f64]) -> (f64, f64) {
+    if series.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    (
+        (mean(series) - median(series)).abs(),
+        maximum(series) - minimum(series),
+    )
+}
+
+/// Checks whether the distribution of a time series looks symmetric, i.e.
+/// whether `|mean - median|` is less than `r` times the range.
+pub fn symmetry_looking(series: &[f64], r: f64) -> bool {
+    let (mean_median_difference, range) = symmetry_terms(series);
+    mean_median_difference < r * range
+}
+
+/// Calculates the correlation between the first-digit distribution of a time
+/// series and Newcomb-Benford's law `P(d) = log10(1 + 1/d)`.
+///
+/// The first digit is taken from the shortest scientific representation of the
+/// absolute value, as tsfresh does. Like `numpy.nan_to_num`, infinite values
+/// count as the largest finite float, while zeros and NaN values have no digit
+/// from 1 to 9 and only count towards the series length. Returns NaN if the
+/// digit distribution is flat, e.g. for an empty or all-zero series.
+pub fn benford_correlation(series: &[f64]) -> f64 {
+    let mut counts = [0usize; 9];
+    for x in series {
+        let x = if x.is_infinite() { f64::MAX } else { x.abs() };
+        let first_digit = format!("{x:e}").as_bytes()[0];
+        if (b'1'..=b'9').contains(&first_digit) {
+            counts[usize::from(first_digit - b'1')] += 1;
+        }
+    }
+    let benford: Vec<f64> = (1..=9)
+        .map(|d| (1.0 + 1.0 / f64::from(d)).log10())
+        .collect();
+    let observed: Vec<f64> = counts
+        .iter()
+        .map(|&c| c as f64 / series.len() as f64)
+        .collect();
+    let (benford_mean, observed_mean) = (mean(&benford), mean(&observed));
+    let mut covariance = 0.0;
+    let mut benford_ss = 0.0;
+    let mut observed_ss = 0.0;
+    for (b, o) in benford.iter().zip(&observed) {
+        covariance += (b - benford_mean) * (o - observed_mean);
+        benford_ss += (b - benford_mean).powi(2);
+        observed_ss += (o - observed_mean).powi(2);
+    }
+    covariance / (benford_ss * observed_ss).sqrt()
+}
+
+/// Calculates the cumulative share of the absolute mass `|x|` of a time series.
+///
+/// Returns `None` if the series has no mass, i.e. is empty or all zero, or
+/// contains NaN values.
+pub(crate) fn cumulative_mass(series: &[f64]) -> Option<Vec<f64>> {
+    let mut running = 0.0;
+    let mut mass: Vec<f64> = series
+        .iter()
+        .map(|x| {
+            running += x.abs();
+            running
+        })
+        .collect();
+    if running.is_nan() || running == 0.0 {
+        return None;
+    }
+    for m in mass.iter_mut() {
+        *m /= running;
+    }
+    Some(mass)
+}
+
+/// Returns the relative index at which the cumulative mass first reaches `q`.
+///
+/// This deviates from tsfresh where no cumulative mass reaches `q`: tsfresh
+/// takes the `argmax` of an all-false mask and returns `1 / n`. Here `q > 1`
+/// and NaN give NaN, and for `q <= 1` the last index counts as reached even if
+/// rounding left the final mass just below 1.
+pub(crate) fn mass_quantile_index(mass: &[f64], q: f64) -> f64 {
+    if q.is_nan() || q > 1.0 {
+        return f64::NAN;
+    }
+    let index = mass.partition_point(|&m| m < q).min(mass.len() - 1);
+    (index + 1) as f64 / mass.len() as f64
+}
+
+/// Calculates the relative index `i / n` at which `q` of the absolute mass of
+/// the time series lies to the left of `i`.
+///
+/// Returns NaN if the series is empty, all zero or contains NaN values, and
+/// if `q` is NaN or larger than 1, where tsfresh returns `1 / n`.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::distribution::index_mass_quantile;
+///
+/// let series = [1.0, 1.0, 1.0, 1.0];
+/// assert_eq!(index_mass_quantile(&series, 0.5), 0.5);
+/// ```
+pub fn index_mass_quantile(series: &[f64], q: f64) -> f64 {
+    cumulative_mass(series).map_or(f64::NAN, |mass| mass_quantile_index(&mass, q))
+}
+
+/// Sorts the non-NaN values of a time series, as used for quantile corridors.
+pub(crate) fn sorted_values(series: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = series.iter().copied().filter(|x| !x.is_nan()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted
+}
+
+/// Collects the consecutive changes `x[i] - x[i - 1]` (or their absolute
+/// values) whose start and end both lie in the corridor between the `ql` and
+/// `qh` quantiles, inclusive.
+///
+/// `sorted` holds the sorted non-NaN values of the series. Returns `None` if
+/// the corridor is empty or degenerate, i.e. `ql >= qh` or both quantiles
+/// coincide.
+pub(crate) fn corridor_changes(
+    series: &[f64],
+    sorted: &[f64],
+    ql: f64,
+    qh: f64,
+    isabs: bool,
+) -> Option<Vec<f64>> {
+    if sorted.is_empty() || ql.is_nan() || qh.is_nan() || ql >= qh {
+        return None;
+    }
+    let (lower, upper) = (quantile_sorted(sorted, ql), quantile_sorted(sorted, qh));
+    if lower == upper {
+        return None;
+    }
+    let inside = |x: f64| lower <= x && x <= upper;
+    let changes = series
+        .windows(2)
+        .filter(|w| inside(w[0]) && inside(w[1]))
+        .map(|w| {
+            let change = w[1] - w[0];
+            if isabs { change.abs() } else { change }
+        })
+        .collect();
+    Some(changes)
+}
+
+/// Aggregates the changes inside a quantile corridor, returning 0 if there are none.
+pub(crate) fn aggregate_corridor_changes(changes: Option<&[f64]>, f_agg: Aggregation) -> f64 {
+    match changes {
+        Some(changes) if !changes.is_empty() => f_agg.apply(changes),
+        _ => 0.0,
+    }
+}
+
+/// Aggregates the consecutive changes of a time series inside the corridor
+/// between its `ql` and `qh` quantiles.
+///
+/// Only changes whose start and end value both lie in the corridor (bounds
+/// included) are considered; with `isabs` their absolute values are used.
+/// Like tsfresh, returns 0 if `ql >= qh`, the quantiles coincide or no change
+/// lies inside the corridor. NaN values are ignored for the quantiles and never
+/// lie inside the corridor. Returns NaN if `ql` or `qh` lies outside `[0, 1]`.
+pub fn change_quantiles(series: &[f64], ql: f64, qh: f64, isabs: bool, f_agg: Aggregation) -> f64 {
+    if !is_unit_interval(ql) || !is_unit_interval(qh) {
+        return f64::NAN;
+    }
+    let changes = corridor_changes(series, &sorted_values(series), ql, qh, isabs);
+    aggregate_corridor_changes(changes.as_deref(), f_agg)
+}
@@ -0,0 +1,515 @@
+//! OOP API for distribution feature extraction.
+
+use std::collections::HashMap;
+
+use crate::features::common::{
+    Aggregation, FeatureFunction, FeatureFunctionReturn, Number, format_feature_name,
+};
+
+use super::functional::{
+    aggregate_corridor_changes, benford_correlation, corridor_changes, cumulative_mass,
+    is_unit_interval, kurtosis, large_standard_deviation, mass_quantile_index, quantile,
+    ratio_beyond_r_sigma, skewness, sorted_values, symmetry_terms,
+    variance_larger_than_standard_deviation, variation_coefficient,
+};
+
+/// Feature function that calculates the sample skewness.
+#[derive(Default)]
+pub struct Skewness;
+
+impl Skewness {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for Skewness {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "skewness".to_string(),
+            value: skewness(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the sample excess kurtosis.
+#[derive(Default)]
+pub struct Kurtosis;
+
+impl Kurtosis {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for Kurtosis {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "kurtosis".to_string(),
+            value: kurtosis(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the variation coefficient.
+#[derive(Default)]
+pub struct VariationCoefficient;
+
+impl VariationCoefficient {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for VariationCoefficient {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "variation_coefficient".to_string(),
+            value: variation_coefficient(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the `q` quantile.
+pub struct Quantile {
+    pub q: Number,
+}
+
+impl Quantile {
+    pub fn new(q: impl Into<Number>) -> Self {
+        Self { q: q.into() }
+    }
+}
+
+impl FeatureFunction for Quantile {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("quantile", &[("q", &self.q)]),
+            value: quantile(series, self.q.as_f64()),
+        }]
+    }
+}
+
+/// Feature function that checks whether the variance is larger than the standard deviation.
+#[derive(Default)]
+pub struct VarianceLargerThanStandardDeviation;
+
+impl VarianceLargerThanStandardDeviation {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for VarianceLargerThanStandardDeviation {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "variance_larger_than_standard_deviation".to_string(),
+            value: f64::from(u8::from(variance_larger_than_standard_deviation(series))),
+        }]
+    }
+}
+
+/// Feature function that calculates the relative index at which the `q`
+/// quantiles of the absolute mass are reached.
+///
+/// The cumulative mass is computed once per series for all `qs`.
+pub struct IndexMassQuantile {
+    pub qs: Vec<Number>,
+}
+
+impl IndexMassQuantile {
+    pub fn new<Q: Into<Number>>(qs: Vec<Q>) -> Self {
+        Self {
+            qs: qs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FeatureFunction for IndexMassQuantile {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mass = cumulative_mass(series);
+        self.qs
+            .iter()
+            .map(|q| FeatureFunctionReturn {
+                name: format_feature_name("index_mass_quantile", &[("q", q)]),
+                value: mass
+                    .as_deref()
+                    .map_or(f64::NAN, |mass| mass_quantile_index(mass, q.as_f64())),
+            })
+            .collect()
+    }
+}
+
+/// Feature function that aggregates the changes inside quantile corridors.
+///
+/// Each parameter is a `(ql, qh, isabs, f_agg)` tuple. The series is sorted
+/// once, and the changes inside each distinct `(ql, qh, isabs)` corridor are
+/// collected once and shared by all aggregations.
+pub struct ChangeQuantiles {
+    pub params: Vec<(Number, Number, bool, Aggregation)>,
+}
+
+impl ChangeQuantiles {
+    pub fn new<Q: Into<Number>>(params: Vec<(Q, Q, bool, Aggregation)>) -> Self {
+        Self {
+            params: params
+                .into_iter()
+                .map(|(ql, qh, isabs, f_agg)| (ql.into(), qh.into(), isabs, f_agg))
+                .collect(),
+        }
+    }
+}
+
+impl FeatureFunction for ChangeQuantiles {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let sorted = sorted_values(series);
+        let mut corridors = HashMap::new();
+        self.params
+            .iter()
+            .map(|(ql_param, qh_param, isabs, f_agg)| {
+                let (ql, qh) = (ql_param.as_f64(), qh_param.as_f64());
+                let changes = corridors
+                    .entry((ql.to_bits(), qh.to_bits(), *isabs))
+                    .or_insert_with(|| corridor_changes(series, &sorted, ql, qh, *isabs));
+                FeatureFunctionReturn {
+                    name: format_feature_name(
+                        "change_quantiles",
+                        &[
+                            ("ql", ql_param),
+                            ("qh", qh_param),
+                            ("isabs", isabs),
+                            ("f_agg", f_agg),
+                        ],
+                    ),
+                    value: if is_unit_interval(ql) && is_unit_interval(qh) {
+                        aggregate_corridor_changes(changes.as_deref(), *f_agg)
+                    } else {
+                        f64::NAN
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Feature function that calculates the fraction of values more than `r`
+/// standard deviations away from the mean.
+pub struct RatioBeyondRSigma {
+    pub r: Number,
+}
+
+impl RatioBeyondRSigma {
+    pub fn new(r: impl Into<Number>) -> Self {
+        Self { r: r.into() }
+    }
+}
+
+impl FeatureFunction for RatioBeyondRSigma {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("ratio_beyond_r_sigma", &[("r", &self.r)]),
+            value: ratio_beyond_r_sigma(series, self.r.as_f64()),
+        }]
+    }
+}
+
+/// Feature function that checks whether the standard deviation exceeds `r`
+/// times the range.
+pub struct LargeStandardDeviation {
+    pub r: Number,
+}
+
+impl LargeStandardDeviation {
+    pub fn new(r: impl Into<Number>) -> Self {
+        Self { r: r.into() }
+    }
+}
+
+impl FeatureFunction for LargeStandardDeviation {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("large_standard_deviation", &[("r", &self.r)]),
+            value: f64::from(u8::from(large_standard_deviation(series, self.r.as_f64()))),
+        }]
+    }
+}
+
+/// Feature function that checks whether the distribution looks symmetric for
+/// each tolerance in `rs`.
+///
+/// Mean, median and range are computed once per series for all `rs`.
+pub struct SymmetryLooking {
+    pub rs: Vec<Number>,
+}
+
+impl SymmetryLooking {
+    pub fn new<R: Into<Number>>(rs: Vec<R>) -> Self {
+        Self {
+            rs: rs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FeatureFunction for SymmetryLooking {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let (mean_median_difference, range) = symmetry_terms(series);
+        self.rs
+            .iter()
+            .map(|r| FeatureFunctionReturn {
+                name: format_feature_name("symmetry_looking", &[("r", r)]),
+                value: f64::from(u8::from(mean_median_difference < r.as_f64() * range)),
+            })
+            .collect()
+    }
+}
+
+/// Feature function that correlates the first-digit distribution with Benford's law.
+#[derive(Default)]
+pub struct BenfordCorrelation;
+
+impl BenfordCorrelation {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for BenfordCorrelation {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "benford_correlation".to_string(),
+            value: benford_correlation(series),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_float_eq, test_utils::synthetic_series};
+
+    #[test]
+    fn test_skewness() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = Skewness::new().apply(&series);
+        assert_eq!(result[0].name, "skewness");
+        assert_float_eq!(result[0].value, 1.697056274847714);
+
+        let symmetric = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = Skewness::new().apply(&symmetric);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let constant = [3.0, 3.0, 3.0];
+        let result = Skewness::new().apply(&constant);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let short = [1.0, 2.0];
+        let result = Skewness::new().apply(&short);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = Kurtosis::new().apply(&series);
+        assert_eq!(result[0].name, "kurtosis");
+        assert_float_eq!(result[0].value, 3.152);
+
+        let constant = [3.0, 3.0, 3.0, 3.0];
+        let result = Kurtosis::new().apply(&constant);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let short = [1.0, 2.0, 3.0];
+        let result = Kurtosis::new().apply(&short);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_variation_coefficient() {
+        let series = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let result = VariationCoefficient::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.7905694150420949);
+
+        let zero_mean = [-1.0, 1.0];
+        let result = VariationCoefficient::new().apply(&zero_mean);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_quantile() {
+        let series = [5.0, 1.0, 4.0, 2.0, 3.0];
+        let result = Quantile::new(0.5).apply(&series);
+        assert_eq!(result[0].name, "quantile__q_0.5");
+        assert_float_eq!(result[0].value, 3.0);
+
+        let result = Quantile::new(0.1).apply(&series);
+        assert_eq!(result[0].name, "quantile__q_0.1");
+        assert_float_eq!(result[0].value, 1.4);
+
+        let result = Quantile::new(1.0).apply(&series);
+        assert_float_eq!(result[0].value, 5.0);
+
+        let empty: [f64; 0] = [];
+        let result = Quantile::new(0.5).apply(&empty);
+        assert!(result[0].value.is_nan());
+
+        for q in [1.5, -0.1, f64::NAN] {
+            let result = Quantile::new(q).apply(&series);
+            assert!(result[0].value.is_nan());
+        }
+
+        let result = Quantile::new(0.5).apply(&[1.0, f64::NAN, 3.0]);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_variance_larger_than_standard_deviation() {
+        let series = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = VarianceLargerThanStandardDeviation::new().apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let narrow = [0.1, 0.2, 0.3];
+        let result = VarianceLargerThanStandardDeviation::new().apply(&narrow);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_index_mass_quantile() {
+        let series = synthetic_series(60);
+        let result = IndexMassQuantile::new(vec![0.1, 0.5, 0.9, 1.0, 1.5]).apply(&series);
+        assert_eq!(result[0].name, "index_mass_quantile__q_0.1");
+        assert_float_eq!(result[0].value, 0.11666666666666667);
+        assert_float_eq!(result[1].value, 0.48333333333333334);
+        assert_float_eq!(result[2].value, 0.85);
+        assert_float_eq!(result[3].value, 1.0);
+        assert!(result[4].value.is_nan());
+
+        let series = [0.0, 0.0, 3.0, 0.0];
+        let result = IndexMassQuantile::new(vec![0.0, 0.5, 1.0]).apply(&series);
+        assert_float_eq!(result[0].value, 0.25);
+        assert_float_eq!(result[1].value, 0.75);
+        assert_float_eq!(result[2].value, 0.75);
+
+        let result = IndexMassQuantile::new(vec![0.5]).apply(&[0.0, 0.0]);
+        assert!(result[0].value.is_nan());
+
+        // Rounding can leave the normalized cumulative sum just below 1. tsfresh
+        // would return 1 / n for both queries, as no mass reaches them.
+        let mass = [0.25, 0.5, 0.9999999999999999];
+        assert_float_eq!(mass_quantile_index(&mass, 1.0), 1.0);
+        assert!(mass_quantile_index(&mass, 1.0 + f64::EPSILON).is_nan());
+        assert!(mass_quantile_index(&mass, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_change_quantiles() {
+        let series = synthetic_series(60);
+        let result = ChangeQuantiles::new(vec![
+            (0.0, 0.2, false, Aggregation::Mean),
+            (0.2, 0.8, true, Aggregation::Var),
+            (0.4, 1.0, false, Aggregation::Median),
+            (0.0, 1.0, true, Aggregation::Std),
+            (0.6, 0.8, false, Aggregation::Var),
+            (0.8, 0.2, false, Aggregation::Mean),
+        ])
+        .apply(&series);
+        assert_eq!(
+            result[0].name,
+            "change_quantiles__f_agg_\"mean\"__isabs_False__qh_0.2__ql_0.0"
+        );
+        assert_float_eq!(result[0].value, 0.0032937746381147734);
+        assert_float_eq!(result[1].value, 0.06509908877528787);
+        assert_float_eq!(result[2].value, -0.059821490458315085);
+        assert_float_eq!(result[3].value, 0.265102952537191);
+        assert_float_eq!(result[4].value, 0.04217292577493067);
+        assert_float_eq!(result[5].value, 0.0);
+
+        // Both quantiles fall on the same value, so the corridor is degenerate.
+        let series = [1.0, 1.0, 1.0, 1.0, 5.0];
+        let result =
+            ChangeQuantiles::new(vec![(0.0, 0.5, false, Aggregation::Mean)]).apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+
+        // Only the change from 2 to 3 starts and ends inside [2, 4].
+        let series = [0.0, 2.0, 3.0, 10.0, 4.0, f64::NAN, 3.0];
+        let result =
+            ChangeQuantiles::new(vec![(0.2, 0.8, false, Aggregation::Mean)]).apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let result = ChangeQuantiles::new(vec![
+            (0.2, 1.5, false, Aggregation::Mean),
+            (-0.5, 0.8, true, Aggregation::Var),
+        ])
+        .apply(&series);
+        assert!(result[0].value.is_nan());
+        assert!(result[1].value.is_nan());
+    }
+
+    #[test]
+    fn test_ratio_beyond_r_sigma() {
+        let series = synthetic_series(60);
+        let result = RatioBeyondRSigma::new(2).apply(&series);
+        assert_eq!(result[0].name, "ratio_beyond_r_sigma__r_2");
+        assert_float_eq!(result[0].value, 0.016666666666666666);
+
+        let result = RatioBeyondRSigma::new(0.5).apply(&series);
+        assert_eq!(result[0].name, "ratio_beyond_r_sigma__r_0.5");
+        assert_float_eq!(result[0].value, 0.6833333333333333);
+
+        let result = RatioBeyondRSigma::new(1).apply(&series);
+        assert_float_eq!(result[0].value, 0.38333333333333336);
+
+        let result = RatioBeyondRSigma::new(3).apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_large_standard_deviation() {
+        let series = synthetic_series(60);
+        let result = LargeStandardDeviation::new(0.25).apply(&series);
+        assert_eq!(result[0].name, "large_standard_deviation__r_0.25");
+        assert_float_eq!(result[0].value, 1.0);
+
+        let result = LargeStandardDeviation::new(0.3).apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let result = LargeStandardDeviation::new(0.0).apply(&[2.0, 2.0]);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_symmetry_looking() {
+        let series = synthetic_series(60);
+        let result = SymmetryLooking::new(vec![0.0, 0.05, 0.1]).apply(&series);
+        assert_eq!(result[1].name, "symmetry_looking__r_0.05");
+        assert_float_eq!(result[0].value, 0.0);
+        assert_float_eq!(result[1].value, 0.0);
+        assert_float_eq!(result[2].value, 1.0);
+
+        let result = SymmetryLooking::new(vec![0.05]).apply(&[1.0, 2.0, 3.0]);
+        assert_float_eq!(result[0].value, 1.0);
+    }
+
+    #[test]
+    fn test_benford_correlation() {
+        let series = synthetic_series(60);
+        let result = BenfordCorrelation::new().apply(&series);
+        assert_eq!(result[0].name, "benford_correlation");
+        assert_float_eq!(result[0].value, 0.7592950938594945);
+
+        let scaled: Vec<f64> = series.iter().map(|x| x * 1000.0).collect();
+        let result = BenfordCorrelation::new().apply(&scaled);
+        assert_float_eq!(result[0].value, 0.7592950938594945);
+
+        let series = [1.0, 2.0, 3.0, 10.0, 11.0, 150.0, 0.02, 7.0];
+        let result = BenfordCorrelation::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.945354800987818);
+
+        let result = BenfordCorrelation::new().apply(&[0.0, 0.0]);
+        assert!(result[0].value.is_nan());
+    }
+}
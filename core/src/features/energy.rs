@@ -0,0 +1,12 @@
+//! Energy feature extraction module.
+//!
+//! Provides features based on the sum of squared values of the series and of
+//! its segments.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{abs_energy, energy_ratio_by_chunks};
+pub use oop::{AbsEnergy, EnergyRatioByChunks};
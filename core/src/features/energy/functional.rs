@@ -0,0 +1,52 @@
+//! Functional API for energy feature extraction.
+
+use std::ops::Range;
+
+/// Calculates the absolute energy of a time series, i.e. the sum of its squared values.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::energy::abs_energy;
+///
+/// assert_eq!(abs_energy(&[1.0, -2.0, 3.0]), 14.0);
+/// ```
+pub fn abs_energy(series: &[f64]) -> f64 {
+    series.iter().map(|x| x * x).sum()
+}
+
+/// Returns the index range of section `index` when splitting `len` values into
+/// `sections` parts like `numpy.array_split`.
+///
+/// The first `len % sections` parts hold one extra value. Parts are empty once
+/// `sections` exceeds `len`. `sections` must be positive.
+pub(crate) fn array_split_range(len: usize, sections: usize, index: usize) -> Range<usize> {
+    let (size, extra) = (len / sections, len % sections);
+    let start = index * size + index.min(extra);
+    let end = start + size + usize::from(index < extra);
+    start..end
+}
+
+/// Calculates the share of the absolute energy in segment `segment_focus` when
+/// the series is split into `num_segments` segments.
+///
+/// Segments follow `numpy.array_split`: the first `n % num_segments` segments
+/// hold one extra value. Returns NaN if the series has zero energy or
+/// `segment_focus` is not smaller than `num_segments`.
+pub fn energy_ratio_by_chunks(series: &[f64], num_segments: usize, segment_focus: usize) -> f64 {
+    energy_ratio(series, abs_energy(series), num_segments, segment_focus)
+}
+
+/// Same as [`energy_ratio_by_chunks`], with the energy of the full series precomputed.
+pub(crate) fn energy_ratio(
+    series: &[f64],
+    full_energy: f64,
+    num_segments: usize,
+    segment_focus: usize,
+) -> f64 {
+    if full_energy == 0.0 || segment_focus >= num_segments {
+        return f64::NAN;
+    }
+    let segment = array_split_range(series.len(), num_segments, segment_focus);
+    abs_energy(&series[segment]) / full_energy
+}
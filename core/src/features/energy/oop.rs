@@ -0,0 +1,122 @@
+//! OOP API for energy feature extraction.
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{abs_energy, energy_ratio};
+
+/// Feature function that calculates the absolute energy.
+#[derive(Default)]
+pub struct AbsEnergy;
+
+impl AbsEnergy {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for AbsEnergy {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "abs_energy".to_string(),
+            value: abs_energy(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the energy shares of segments.
+///
+/// Each parameter is a `(num_segments, segment_focus)` pair. The energy of the
+/// full series is computed once per series.
+pub struct EnergyRatioByChunks {
+    pub params: Vec<(usize, usize)>,
+}
+
+impl EnergyRatioByChunks {
+    pub fn new(params: Vec<(usize, usize)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for EnergyRatioByChunks {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let full_energy = abs_energy(series);
+        self.params
+            .iter()
+            .map(|(num_segments, segment_focus)| FeatureFunctionReturn {
+                name: format_feature_name(
+                    "energy_ratio_by_chunks",
+                    &[
+                        ("num_segments", num_segments),
+                        ("segment_focus", segment_focus),
+                    ],
+                ),
+                value: energy_ratio(series, full_energy, *num_segments, *segment_focus),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_float_eq, features::energy::functional::array_split_range,
+        test_utils::synthetic_series,
+    };
+
+    #[test]
+    fn test_abs_energy() {
+        let result = AbsEnergy::new().apply(&synthetic_series(23));
+        assert_eq!(result[0].name, "abs_energy");
+        assert_float_eq!(result[0].value, 11.17702465884206);
+
+        let result = AbsEnergy::new().apply(&[]);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_array_split_range() {
+        // numpy.array_split(range(7), 3) -> [0, 1, 2], [3, 4], [5, 6]
+        assert_eq!(array_split_range(7, 3, 0), 0..3);
+        assert_eq!(array_split_range(7, 3, 1), 3..5);
+        assert_eq!(array_split_range(7, 3, 2), 5..7);
+        // numpy.array_split(range(2), 4) -> [0], [1], [], []
+        assert_eq!(array_split_range(2, 4, 1), 1..2);
+        assert_eq!(array_split_range(2, 4, 3), 2..2);
+    }
+
+    #[test]
+    fn test_energy_ratio_by_chunks() {
+        let series = synthetic_series(23);
+        let result = EnergyRatioByChunks::new(vec![
+            (10, 0),
+            (10, 9),
+            (3, 1),
+            (5, 4),
+            (30, 25),
+            (30, 3),
+            (3, 3),
+        ])
+        .apply(&series);
+        assert_eq!(
+            result[0].name,
+            "energy_ratio_by_chunks__num_segments_10__segment_focus_0"
+        );
+        assert_float_eq!(result[0].value, 0.0643376126840068);
+        assert_float_eq!(result[1].value, 0.03255118730154852);
+        assert_float_eq!(result[2].value, 0.4266179223564981);
+        assert_float_eq!(result[3].value, 0.05164546803767147);
+        assert_float_eq!(result[4].value, 0.0);
+        assert_float_eq!(result[5].value, 0.012164489237352907);
+        assert!(result[6].value.is_nan());
+
+        let total: f64 = (0..10)
+            .map(|focus| EnergyRatioByChunks::new(vec![(10, focus)]).apply(&series)[0].value)
+            .sum();
+        assert_float_eq!(total, 1.0);
+
+        let result = EnergyRatioByChunks::new(vec![(2, 0)]).apply(&[0.0, 0.0]);
+        assert!(result[0].value.is_nan());
+    }
+}
@@ -0,0 +1,19 @@
+//! Entropy feature extraction module.
+//!
+//! Provides entropy estimates of the value distribution, of ordinal patterns,
+//! of the power spectrum and of template recurrences (sample and approximate entropy),
+//! as well as the Lempel-Ziv complexity.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    approximate_entropy, binned_entropy, fourier_entropy, lempel_ziv_complexity,
+    permutation_entropy, sample_entropy,
+};
+pub use oop::{
+    ApproximateEntropy, BinnedEntropy, FourierEntropy, LempelZivComplexity, PermutationEntropy,
+    SampleEntropy,
+};
@@ -0,0 +1,261 @@
+//! Functional API for entropy feature extraction.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::features::{
+    minimal::{maximum, minimum, standard_deviation},
+    spectral::{WelchConfig, welch},
+};
+
+/// Counts the values falling into `bins` equal-width bins between the minimum
+/// and the maximum, like `numpy.histogram(values, bins)`.
+///
+/// The last bin includes the maximum. A constant input is binned over
+/// `[value - 0.5, value + 0.5]`.
+pub(crate) fn histogram(values: &[f64], bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    if values.is_empty() || bins == 0 {
+        return counts;
+    }
+    let (mut first, mut last) = (minimum(values), maximum(values));
+    if first == last {
+        first -= 0.5;
+        last += 0.5;
+    }
+    let step = (last - first) / bins as f64;
+    let edge = |i: usize| {
+        if i == bins {
+            last
+        } else {
+            first + i as f64 * step
+        }
+    };
+    let norm = bins as f64 / (last - first);
+    for &x in values {
+        // Guess the bin from the scaled value, then correct rounding errors
+        // against the actual edges as numpy does.
+        let mut index = (((x - first) * norm) as usize).min(bins - 1);
+        if x < edge(index) {
+            index -= 1;
+        } else if index != bins - 1 && x >= edge(index + 1) {
+            index += 1;
+        }
+        counts[index] += 1;
+    }
+    counts
+}
+
+/// Shannon entropy (in nats) of the given counts relative to `total`.
+fn entropy_of_counts(counts: impl IntoIterator<Item = usize>, total: usize) -> f64 {
+    -counts
+        .into_iter()
+        .filter(|&count| count > 0)
+        .map(|count| {
+            let p = count as f64 / total as f64;
+            p * p.ln()
+        })
+        .sum::<f64>()
+}
+
+/// Calculates the entropy of the values binned into `max_bins` equal-width bins.
+///
+/// Returns NaN if the series is empty or contains NaN values.
+pub fn binned_entropy(series: &[f64], max_bins: usize) -> f64 {
+    if series.is_empty() || series.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    entropy_of_counts(histogram(series, max_bins), series.len())
+}
+
+/// Calculates the binned entropy of the Welch power spectral density,
+/// normalized by its maximum.
+///
+/// The density uses tsfresh's settings, i.e. segments of at most 256 values.
+/// Returns NaN if the spectrum is empty or vanishes everywhere.
+pub fn fourier_entropy(series: &[f64], bins: usize) -> f64 {
+    let density = welch(series, &WelchConfig::DEFAULT);
+    if density.is_empty() {
+        return f64::NAN;
+    }
+    let max = maximum(&density);
+    if max.is_nan() || max <= 0.0 {
+        return f64::NAN;
+    }
+    let normalized: Vec<f64> = density.iter().map(|p| p / max).collect();
+    binned_entropy(&normalized, bins)
+}
+
+/// Calculates the permutation entropy of the ordinal patterns of length `dimension`.
+///
+/// Like tsfresh, the windows are contiguous and start every `tau` values. Ties
+/// are ranked by position. Returns NaN if the series is shorter than
+/// `dimension` or `tau` is zero.
+pub fn permutation_entropy(series: &[f64], tau: usize, dimension: usize) -> f64 {
+    if tau == 0 || dimension == 0 || series.len() < dimension {
+        return f64::NAN;
+    }
+    let compare = |a: f64, b: f64| {
+        a.partial_cmp(&b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+    };
+    // Patterns are kept in lexicographic order so the sum is reproducible.
+    let mut patterns: BTreeMap<Vec<usize>, usize> = BTreeMap::new();
+    let mut order: Vec<usize> = Vec::with_capacity(dimension);
+    let mut windows = 0;
+    for window in series.windows(dimension).step_by(tau) {
+        order.clear();
+        order.extend(0..dimension);
+        order.sort_by(|&a, &b| compare(window[a], window[b]));
+        let mut ranks = vec![0; dimension];
+        for (rank, &position) in order.iter().enumerate() {
+            ranks[position] = rank;
+        }
+        *patterns.entry(ranks).or_insert(0) += 1;
+        windows += 1;
+    }
+    entropy_of_counts(patterns.into_values(), windows)
+}
+
+/// Visits every unordered pair of templates of length `m` whose Chebyshev
+/// distance is at most `tolerance`.
+///
+/// Templates are sorted by their first value, so only pairs whose first values
+/// are within `tolerance` of each other are compared. The callback receives the
+/// start indices of both templates and whether they also match when extended to
+/// length `m + 1` (always false if either extension runs past the series).
+fn for_each_template_match(
+    series: &[f64],
+    m: usize,
+    tolerance: f64,
+    mut on_match: impl FnMut(usize, usize, bool),
+) {
+    let n = series.len();
+    let mut starts: Vec<usize> = (0..=n - m).collect();
+    starts.sort_by(|&a, &b| series[a].partial_cmp(&series[b]).unwrap_or(Ordering::Equal));
+    for (position, &i) in starts.iter().enumerate() {
+        for &j in &starts[position + 1..] {
+            if series[j] - series[i] > tolerance {
+                break;
+            }
+            if (1..m).all(|k| (series[i + k] - series[j + k]).abs() <= tolerance) {
+                let extended =
+                    i + m < n && j + m < n && (series[i + m] - series[j + m]).abs() <= tolerance;
+                on_match(i, j, extended);
+            }
+        }
+    }
+}
+
+/// Calculates the sample entropy with template length 2 and a tolerance of
+/// 0.2 standard deviations.
+///
+/// Follows tsfresh, which counts matches among all templates of each length.
+/// Uses a sorted neighbour search instead of comparing all pairs. Returns NaN
+/// if the series contains NaN values or no templates match, and infinity if
+/// only the shorter templates match.
+pub fn sample_entropy(series: &[f64]) -> f64 {
+    const M: usize = 2;
+    if series.len() <= M || series.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    let tolerance = 0.2 * standard_deviation(series);
+    let (mut matches, mut extended_matches) = (0u64, 0u64);
+    for_each_template_match(series, M, tolerance, |_, _, extended| {
+        matches += 1;
+        extended_matches += u64::from(extended);
+    });
+    // Pairs are counted in both directions, which cancels in the ratio.
+    -(extended_matches as f64 / matches as f64).ln()
+}
+
+/// Calculates the approximate entropy with template length `m` and a tolerance
+/// of `r` standard deviations.
+///
+/// Uses a sorted neighbour search instead of comparing all pairs. Returns 0 for
+/// series with at most `m + 1` values, like tsfresh, and NaN if `m` is zero,
+/// `r` is negative or the series contains NaN values.
+pub fn approximate_entropy(series: &[f64], m: usize, r: f64) -> f64 {
+    let n = series.len();
+    if m == 0 || r.is_nan() || r < 0.0 || series.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    if n <= m + 1 {
+        return 0.0;
+    }
+    let tolerance = r * standard_deviation(series);
+    // Every template matches itself.
+    let mut counts = vec![1usize; n - m + 1];
+    let mut extended_counts = vec![1usize; n - m];
+    for_each_template_match(series, m, tolerance, |i, j, extended| {
+        counts[i] += 1;
+        counts[j] += 1;
+        if extended {
+            extended_counts[i] += 1;
+            extended_counts[j] += 1;
+        }
+    });
+    let phi = |counts: &[usize]| {
+        let templates = counts.len() as f64;
+        counts
+            .iter()
+            .map(|&count| (count as f64 / templates).ln())
+            .sum::<f64>()
+            / templates
+    };
+    (phi(&counts) - phi(&extended_counts)).abs()
+}
+
+/// Calculates the Lempel-Ziv (LZ76) complexity of the series discretized into
+/// `bins` equal-width bins.
+///
+/// The discretized series is parsed from left to right into the shortest
+/// sub-words not seen before; the result is the number of distinct sub-words
+/// divided by the series length. Returns NaN if the series is empty or contains
+/// NaN values.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::entropy::lempel_ziv_complexity;
+///
+/// // "100111" parses into 1, 0, 01 and 11.
+/// let series = [1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+/// assert_eq!(lempel_ziv_complexity(&series, 2), 4.0 / 6.0);
+/// ```
+pub fn lempel_ziv_complexity(series: &[f64], bins: usize) -> f64 {
+    let n = series.len();
+    if n == 0 || series.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    // Symbol of a value = number of upper bin edges below it, like
+    // `numpy.searchsorted(edges, x, side="left")`.
+    let (first, last) = (minimum(series), maximum(series));
+    let step = (last - first) / bins as f64;
+    let edges: Vec<f64> = (1..=bins)
+        .map(|i| {
+            if i == bins {
+                last
+            } else {
+                first + i as f64 * step
+            }
+        })
+        .collect();
+    let symbols: Vec<usize> = series
+        .iter()
+        .map(|&x| edges.partition_point(|&edge| edge < x))
+        .collect();
+
+    let mut words: HashSet<&[usize]> = HashSet::new();
+    let (mut start, mut length) = (0, 1);
+    while start + length <= n {
+        let word = &symbols[start..start + length];
+        if words.insert(word) {
+            start += length;
+            length = 1;
+        } else {
+            length += 1;
+        }
+    }
+    words.len() as f64 / n as f64
+}
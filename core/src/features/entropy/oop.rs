@@ -0,0 +1,282 @@
+//! OOP API for entropy feature extraction.
+
+use crate::features::common::{
+    FeatureFunction, FeatureFunctionReturn, Number, format_feature_name,
+};
+
+use super::functional::{
+    approximate_entropy, binned_entropy, fourier_entropy, lempel_ziv_complexity,
+    permutation_entropy, sample_entropy,
+};
+
+/// Feature function that calculates the sample entropy.
+#[derive(Default)]
+pub struct SampleEntropy;
+
+impl SampleEntropy {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for SampleEntropy {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "sample_entropy".to_string(),
+            value: sample_entropy(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the approximate entropy for template
+/// length `m` and tolerance `r` (in standard deviations).
+pub struct ApproximateEntropy {
+    pub m: usize,
+    pub r: Number,
+}
+
+impl ApproximateEntropy {
+    pub fn new(m: usize, r: impl Into<Number>) -> Self {
+        Self { m, r: r.into() }
+    }
+}
+
+impl FeatureFunction for ApproximateEntropy {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("approximate_entropy", &[("m", &self.m), ("r", &self.r)]),
+            value: approximate_entropy(series, self.m, self.r.as_f64()),
+        }]
+    }
+}
+
+/// Feature function that calculates the permutation entropy of ordinal
+/// patterns of length `dimension`, taken every `tau` values.
+pub struct PermutationEntropy {
+    pub tau: usize,
+    pub dimension: usize,
+}
+
+impl PermutationEntropy {
+    pub fn new(tau: usize, dimension: usize) -> Self {
+        Self { tau, dimension }
+    }
+}
+
+impl FeatureFunction for PermutationEntropy {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name(
+                "permutation_entropy",
+                &[("dimension", &self.dimension), ("tau", &self.tau)],
+            ),
+            value: permutation_entropy(series, self.tau, self.dimension),
+        }]
+    }
+}
+
+/// Feature function that calculates the entropy of the values binned into
+/// `max_bins` equal-width bins.
+pub struct BinnedEntropy {
+    pub max_bins: usize,
+}
+
+impl BinnedEntropy {
+    pub fn new(max_bins: usize) -> Self {
+        Self { max_bins }
+    }
+}
+
+impl FeatureFunction for BinnedEntropy {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("binned_entropy", &[("max_bins", &self.max_bins)]),
+            value: binned_entropy(series, self.max_bins),
+        }]
+    }
+}
+
+/// Feature function that calculates the binned entropy of the power spectral
+/// density with `bins` bins.
+pub struct FourierEntropy {
+    pub bins: usize,
+}
+
+impl FourierEntropy {
+    pub fn new(bins: usize) -> Self {
+        Self { bins }
+    }
+}
+
+impl FeatureFunction for FourierEntropy {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("fourier_entropy", &[("bins", &self.bins)]),
+            value: fourier_entropy(series, self.bins),
+        }]
+    }
+}
+
+/// Feature function that calculates the Lempel-Ziv complexity of the series
+/// discretized into `bins` bins.
+pub struct LempelZivComplexity {
+    pub bins: usize,
+}
+
+impl LempelZivComplexity {
+    pub fn new(bins: usize) -> Self {
+        Self { bins }
+    }
+}
+
+impl FeatureFunction for LempelZivComplexity {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("lempel_ziv_complexity", &[("bins", &self.bins)]),
+            value: lempel_ziv_complexity(series, self.bins),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_float_eq, test_utils::synthetic_series};
+
+    fn long_series() -> Vec<f64> {
+        (0..400)
+            .map(|i| {
+                let t = i as f64;
+                3.0 * (0.01 * t * t).sin() + (0.7 * t).cos()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sample_entropy() {
+        let result = SampleEntropy::new().apply(&synthetic_series(60));
+        assert_eq!(result[0].name, "sample_entropy");
+        assert_float_eq!(result[0].value, 1.791759469228055);
+
+        let result = SampleEntropy::new().apply(&long_series());
+        assert_float_eq!(result[0].value, 1.4223704175937002);
+
+        let result = SampleEntropy::new().apply(&[1.0, 2.0, f64::NAN, 4.0]);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_approximate_entropy() {
+        let series = synthetic_series(60);
+        let result = ApproximateEntropy::new(2, 0.1).apply(&series);
+        assert_eq!(result[0].name, "approximate_entropy__m_2__r_0.1");
+        assert_float_eq!(result[0].value, 0.19149455330651532);
+
+        let cases = [
+            (2, 0.5, 0.8041279218861477),
+            (3, 0.9, 0.4209472226144464),
+            (1, 0.3, 1.2284564510323799),
+        ];
+        for (m, r, expected) in cases {
+            let result = ApproximateEntropy::new(m, r).apply(&series);
+            assert_float_eq!(result[0].value, expected);
+        }
+
+        let result = ApproximateEntropy::new(2, 0.3).apply(&long_series());
+        assert_float_eq!(result[0].value, 1.1699207519835007);
+
+        let result = ApproximateEntropy::new(2, 0.3).apply(&[1.0, 2.0, 3.0]);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_permutation_entropy() {
+        let series = synthetic_series(60);
+        let result = PermutationEntropy::new(1, 3).apply(&series);
+        assert_eq!(result[0].name, "permutation_entropy__dimension_3__tau_1");
+        assert_float_eq!(result[0].value, 1.6875224594190454);
+
+        let cases = [
+            (2, 4, 2.2434520879279987),
+            (1, 5, 3.3999184913004203),
+            (3, 7, 2.890371757896165),
+        ];
+        for (tau, dimension, expected) in cases {
+            let result = PermutationEntropy::new(tau, dimension).apply(&series);
+            assert_float_eq!(result[0].value, expected);
+        }
+
+        // Reference example from Bandt & Pompe (2002).
+        let result = PermutationEntropy::new(1, 3).apply(&[4.0, 7.0, 9.0, 10.0, 6.0, 11.0, 3.0]);
+        assert_float_eq!(result[0].value, 1.0549201679861442);
+
+        let result = PermutationEntropy::new(1, 3).apply(&[1.0, 2.0]);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_binned_entropy() {
+        let series = synthetic_series(60);
+        let result = BinnedEntropy::new(10).apply(&series);
+        assert_eq!(result[0].name, "binned_entropy__max_bins_10");
+        assert_float_eq!(result[0].value, 2.211884296081516);
+
+        for (max_bins, expected) in [(3, 1.080527626604172), (1, 0.0), (100, 3.730329367149977)] {
+            let result = BinnedEntropy::new(max_bins).apply(&series);
+            assert_float_eq!(result[0].value, expected);
+        }
+
+        let result = BinnedEntropy::new(5).apply(&[2.0; 10]);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_fourier_entropy() {
+        let series = synthetic_series(60);
+        let result = FourierEntropy::new(2).apply(&series);
+        assert_eq!(result[0].name, "fourier_entropy__bins_2");
+        assert_float_eq!(result[0].value, 0.1425058673927378);
+
+        let cases = [
+            (5, 0.28393626675586414),
+            (10, 0.42425409852044593),
+            (100, 1.237819727689966),
+        ];
+        for (bins, expected) in cases {
+            let result = FourierEntropy::new(bins).apply(&series);
+            assert_float_eq!(result[0].value, expected);
+        }
+
+        let result = FourierEntropy::new(10).apply(&long_series());
+        assert_float_eq!(result[0].value, 0.9471814440181486);
+
+        let result = FourierEntropy::new(10).apply(&[1.0; 8]);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_lempel_ziv_complexity() {
+        let series = synthetic_series(60);
+        let result = LempelZivComplexity::new(2).apply(&series);
+        assert_eq!(result[0].name, "lempel_ziv_complexity__bins_2");
+        assert_float_eq!(result[0].value, 0.2833333333333333);
+
+        let cases = [
+            (3, 0.36666666666666664),
+            (5, 0.43333333333333335),
+            (10, 0.5166666666666667),
+            (100, 0.85),
+        ];
+        for (bins, expected) in cases {
+            let result = LempelZivComplexity::new(bins).apply(&series);
+            assert_float_eq!(result[0].value, expected);
+        }
+
+        let result = LempelZivComplexity::new(4).apply(&[3.0; 5]);
+        assert_float_eq!(result[0].value, 0.4);
+
+        let result = LempelZivComplexity::new(4).apply(&[]);
+        assert!(result[0].value.is_nan());
+    }
+}
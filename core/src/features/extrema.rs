@@ -0,0 +1,18 @@
+//! Extrema feature extraction module.
+//!
+//! Provides features describing where the extreme values of a time series occur,
+//! whether they are repeated and how large the largest absolute values are. Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    first_location_of_maximum, first_location_of_minimum, has_duplicate, has_duplicate_max,
+    has_duplicate_min, last_location_of_maximum, last_location_of_minimum, mean_n_absolute_max,
+};
+pub use oop::{
+    ExtremaFeatureSet, FirstLocationOfMaximum, FirstLocationOfMinimum, HasDuplicate,
+    HasDuplicateMax, HasDuplicateMin, LastLocationOfMaximum, LastLocationOfMinimum,
+    MeanNAbsoluteMax,
+};
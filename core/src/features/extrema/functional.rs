@@ -0,0 +1,86 @@
+//! Functional API for extrema feature extraction.
+
+use crate::features::minimal::{maximum, mean, minimum};
+
+/// Calculates the relative first location of the maximum value of a time series.
+///
+/// Returns NaN for an empty series.
+pub fn first_location_of_maximum(series: &[f64]) -> f64 {
+    let max = maximum(series);
+    match series.iter().position(|&x| x == max) {
+        Some(i) => i as f64 / series.len() as f64,
+        None => f64::NAN,
+    }
+}
+
+/// Calculates the relative last location of the maximum value of a time series.
+///
+/// Returns NaN for an empty series.
+pub fn last_location_of_maximum(series: &[f64]) -> f64 {
+    let max = maximum(series);
+    match series.iter().rposition(|&x| x == max) {
+        Some(i) => (i + 1) as f64 / series.len() as f64,
+        None => f64::NAN,
+    }
+}
+
+/// Calculates the relative first location of the minimum value of a time series.
+///
+/// Returns NaN for an empty series.
+pub fn first_location_of_minimum(series: &[f64]) -> f64 {
+    let min = minimum(series);
+    match series.iter().position(|&x| x == min) {
+        Some(i) => i as f64 / series.len() as f64,
+        None => f64::NAN,
+    }
+}
+
+/// Calculates the relative last location of the minimum value of a time series.
+///
+/// Returns NaN for an empty series.
+pub fn last_location_of_minimum(series: &[f64]) -> f64 {
+    let min = minimum(series);
+    match series.iter().rposition(|&x| x == min) {
+        Some(i) => (i + 1) as f64 / series.len() as f64,
+        None => f64::NAN,
+    }
+}
+
+/// Checks whether any value occurs more than once in a time series.
+///
+/// Like `numpy.unique`, all NaN values count as the same value.
+pub fn has_duplicate(series: &[f64]) -> bool {
+    let mut sorted: Vec<f64> = series.iter().copied().filter(|x| !x.is_nan()).collect();
+    if series.len() - sorted.len() >= 2 {
+        return true;
+    }
+    sorted.sort_by(f64::total_cmp);
+    sorted.windows(2).any(|w| w[0] == w[1])
+}
+
+/// Checks whether the maximum value occurs more than once in a time series.
+pub fn has_duplicate_max(series: &[f64]) -> bool {
+    let max = maximum(series);
+    series.iter().filter(|&&x| x == max).count() >= 2
+}
+
+/// Checks whether the minimum value occurs more than once in a time series.
+pub fn has_duplicate_min(series: &[f64]) -> bool {
+    let min = minimum(series);
+    series.iter().filter(|&&x| x == min).count() >= 2
+}
+
+/// Calculates the mean of the `number_of_maxima` largest absolute values of a
+/// time series.
+///
+/// Returns NaN unless the series is longer than `number_of_maxima`, as tsfresh
+/// does, or if `number_of_maxima` is zero. NaN values sort above all others, as
+/// in `numpy.sort`, so they make the result NaN once they are among the maxima.
+pub fn mean_n_absolute_max(series: &[f64], number_of_maxima: usize) -> f64 {
+    if number_of_maxima == 0 || series.len() <= number_of_maxima {
+        return f64::NAN;
+    }
+    let mut absolute: Vec<f64> = series.iter().map(|x| x.abs()).collect();
+    absolute.sort_by(|a, b| b.total_cmp(a));
+    mean(&absolute[..number_of_maxima])
+}
@@ -0,0 +1,338 @@
+//! OOP API for extrema feature extraction.
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{
+    first_location_of_maximum, first_location_of_minimum, has_duplicate, has_duplicate_max,
+    has_duplicate_min, last_location_of_maximum, last_location_of_minimum, mean_n_absolute_max,
+};
+
+/// Feature function that calculates the relative first location of the maximum value.
+#[derive(Default)]
+pub struct FirstLocationOfMaximum;
+
+impl FirstLocationOfMaximum {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for FirstLocationOfMaximum {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "first_location_of_maximum".to_string(),
+            value: first_location_of_maximum(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the relative last location of the maximum value.
+#[derive(Default)]
+pub struct LastLocationOfMaximum;
+
+impl LastLocationOfMaximum {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for LastLocationOfMaximum {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "last_location_of_maximum".to_string(),
+            value: last_location_of_maximum(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the relative first location of the minimum value.
+#[derive(Default)]
+pub struct FirstLocationOfMinimum;
+
+impl FirstLocationOfMinimum {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for FirstLocationOfMinimum {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "first_location_of_minimum".to_string(),
+            value: first_location_of_minimum(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the relative last location of the minimum value.
+#[derive(Default)]
+pub struct LastLocationOfMinimum;
+
+impl LastLocationOfMinimum {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for LastLocationOfMinimum {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "last_location_of_minimum".to_string(),
+            value: last_location_of_minimum(series),
+        }]
+    }
+}
+
+/// Feature function that checks whether the maximum value occurs more than once.
+#[derive(Default)]
+pub struct HasDuplicateMax;
+
+impl HasDuplicateMax {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for HasDuplicateMax {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "has_duplicate_max".to_string(),
+            value: f64::from(u8::from(has_duplicate_max(series))),
+        }]
+    }
+}
+
+/// Feature function that checks whether the minimum value occurs more than once.
+#[derive(Default)]
+pub struct HasDuplicateMin;
+
+impl HasDuplicateMin {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for HasDuplicateMin {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "has_duplicate_min".to_string(),
+            value: f64::from(u8::from(has_duplicate_min(series))),
+        }]
+    }
+}
+
+/// Feature function that checks whether any value occurs more than once.
+#[derive(Default)]
+pub struct HasDuplicate;
+
+impl HasDuplicate {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for HasDuplicate {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "has_duplicate".to_string(),
+            value: f64::from(u8::from(has_duplicate(series))),
+        }]
+    }
+}
+
+/// Feature function that calculates the mean of the `number_of_maxima` largest absolute values.
+pub struct MeanNAbsoluteMax {
+    pub number_of_maxima: usize,
+}
+
+impl MeanNAbsoluteMax {
+    pub fn new(number_of_maxima: usize) -> Self {
+        Self { number_of_maxima }
+    }
+}
+
+impl FeatureFunction for MeanNAbsoluteMax {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name(
+                "mean_n_absolute_max",
+                &[("number_of_maxima", &self.number_of_maxima)],
+            ),
+            value: mean_n_absolute_max(series, self.number_of_maxima),
+        }]
+    }
+}
+
+/// Aggregates and computes all extrema feature functions for a given time series.
+#[derive(Default)]
+pub struct ExtremaFeatureSet {}
+
+impl ExtremaFeatureSet {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl FeatureFunction for ExtremaFeatureSet {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut results = Vec::new();
+
+        results.extend(FirstLocationOfMaximum::DEFAULT.apply(series));
+        results.extend(LastLocationOfMaximum::DEFAULT.apply(series));
+        results.extend(FirstLocationOfMinimum::DEFAULT.apply(series));
+        results.extend(LastLocationOfMinimum::DEFAULT.apply(series));
+        results.extend(HasDuplicateMax::DEFAULT.apply(series));
+        results.extend(HasDuplicateMin::DEFAULT.apply(series));
+        results.extend(HasDuplicate::DEFAULT.apply(series));
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_first_location_of_maximum() {
+        let series = [1.0, 5.0, 3.0, 5.0];
+        let result = FirstLocationOfMaximum::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.25);
+
+        let single = [7.0];
+        let result = FirstLocationOfMaximum::new().apply(&single);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let empty: [f64; 0] = [];
+        let result = FirstLocationOfMaximum::new().apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_last_location_of_maximum() {
+        let series = [1.0, 5.0, 3.0, 5.0];
+        let result = LastLocationOfMaximum::new().apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let series = [1.0, 5.0, 3.0, 2.0];
+        let result = LastLocationOfMaximum::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.5);
+
+        let empty: [f64; 0] = [];
+        let result = LastLocationOfMaximum::new().apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_first_location_of_minimum() {
+        let series = [3.0, 1.0, 2.0, 1.0];
+        let result = FirstLocationOfMinimum::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.25);
+
+        let empty: [f64; 0] = [];
+        let result = FirstLocationOfMinimum::new().apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_last_location_of_minimum() {
+        let series = [3.0, 1.0, 2.0, 1.0, 4.0];
+        let result = LastLocationOfMinimum::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.8);
+
+        let empty: [f64; 0] = [];
+        let result = LastLocationOfMinimum::new().apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_has_duplicate_max() {
+        let series = [1.0, 5.0, 3.0, 5.0];
+        let result = HasDuplicateMax::new().apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let series = [1.0, 5.0, 1.0, 4.0];
+        let result = HasDuplicateMax::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_has_duplicate_min() {
+        let series = [1.0, 5.0, 1.0, 4.0];
+        let result = HasDuplicateMin::new().apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let series = [1.0, 5.0, 3.0, 5.0];
+        let result = HasDuplicateMin::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_has_duplicate() {
+        let series = [2.0, 5.0, 3.0, 2.0];
+        let result = HasDuplicate::new().apply(&series);
+        assert_float_eq!(result[0].value, 1.0);
+
+        let series = [1.0, 2.0, 3.0];
+        let result = HasDuplicate::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let empty: [f64; 0] = [];
+        let result = HasDuplicate::new().apply(&empty);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let result = HasDuplicate::new().apply(&[1.0, f64::NAN, 2.0]);
+        assert_float_eq!(result[0].value, 0.0);
+
+        let result = HasDuplicate::new().apply(&[f64::NAN, 1.0, f64::NAN]);
+        assert_float_eq!(result[0].value, 1.0);
+    }
+
+    #[test]
+    fn test_mean_n_absolute_max() {
+        let series = [-7.0, 1.0, 5.0, -2.0, 6.0];
+        let result = MeanNAbsoluteMax::new(2).apply(&series);
+        assert_eq!(result[0].name, "mean_n_absolute_max__number_of_maxima_2");
+        assert_float_eq!(result[0].value, 6.5);
+
+        let result = MeanNAbsoluteMax::new(1).apply(&series);
+        assert_float_eq!(result[0].value, 7.0);
+
+        let result = MeanNAbsoluteMax::new(5).apply(&series);
+        assert!(result[0].value.is_nan());
+
+        let result = MeanNAbsoluteMax::new(0).apply(&series);
+        assert!(result[0].value.is_nan());
+
+        let series = [-7.0, f64::NAN, 5.0, -2.0, 6.0];
+        let result = MeanNAbsoluteMax::new(1).apply(&series);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_extrema_feature_set() {
+        let series = [1.0, 5.0, 3.0, 5.0];
+        let result = ExtremaFeatureSet::new().apply(&series);
+        let names: Vec<&str> = result.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "first_location_of_maximum",
+                "last_location_of_maximum",
+                "first_location_of_minimum",
+                "last_location_of_minimum",
+                "has_duplicate_max",
+                "has_duplicate_min",
+                "has_duplicate",
+            ]
+        );
+    }
+}
@@ -0,0 +1,14 @@
+//! Langevin model feature extraction module.
+//!
+//! Provides features of the deterministic dynamics `h(x)` of a Langevin model
+//! `dx/dt = h(x) + noise`, estimated with the method of Friedrich et al. (2000).
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    estimate_friedrich_coefficients, friedrich_coefficients, max_langevin_fixed_point,
+};
+pub use oop::{FriedrichCoefficients, MaxLangevinFixedPoint};
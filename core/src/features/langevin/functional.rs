@@ -0,0 +1,86 @@
+//! Functional API for Langevin model feature extraction.
+
+use crate::features::distribution::quantile_sorted;
+use crate::math::{polyfit, polynomial_roots};
+
+/// Estimates the coefficients of the polynomial `h(x)` of degree `m` that
+/// describes the deterministic dynamics of the series.
+///
+/// Mirrors tsfresh's `_estimate_friedrich_coefficients`: the values `x[t]` are
+/// binned into `r` quantile bins, the mean value and the mean change
+/// `x[t + 1] - x[t]` are computed per non-empty bin, and a polynomial is fitted
+/// through these means. The coefficients are ordered with the highest power
+/// first.
+///
+/// Returns `None` if `m` or `r` is zero, the series has fewer than two values or
+/// contains NaN, the quantile bins are not distinct, or there are fewer
+/// non-empty bins than coefficients.
+pub fn estimate_friedrich_coefficients(series: &[f64], m: usize, r: usize) -> Option<Vec<f64>> {
+    if m == 0 || r == 0 || series.len() < 2 || series.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    let signal = &series[..series.len() - 1];
+    let mut sorted = signal.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    // Bin edges at the quantiles `numpy.linspace(0, 1, r + 1)`, as in `pandas.qcut`.
+    let step = 1.0 / r as f64;
+    let edges: Vec<f64> = (0..=r)
+        .map(|i| {
+            let q = if i == r { 1.0 } else { i as f64 * step };
+            quantile_sorted(&sorted, q)
+        })
+        .collect();
+    if edges.windows(2).any(|w| w[0] == w[1]) {
+        return None;
+    }
+
+    // Bins are right-closed, and the first one also includes its left edge.
+    let mut sums = vec![(0.0, 0.0, 0usize); r];
+    for (t, &x) in signal.iter().enumerate() {
+        let bin = edges.partition_point(|&edge| edge < x).max(1) - 1;
+        let (value_sum, change_sum, count) = &mut sums[bin];
+        *value_sum += x;
+        *change_sum += series[t + 1] - x;
+        *count += 1;
+    }
+    let (x_means, y_means): (Vec<f64>, Vec<f64>) = sums
+        .into_iter()
+        .filter(|&(_, _, count)| count > 0)
+        .map(|(value_sum, change_sum, count)| (value_sum / count as f64, change_sum / count as f64))
+        .unzip();
+    polyfit(&x_means, &y_means, m)
+}
+
+/// Returns coefficient `coeff` of the Friedrich polynomial `h(x)` of degree `m`
+/// estimated with `r` quantile bins.
+///
+/// Coefficients are ordered with the highest power first, so `coeff = 0` is the
+/// coefficient of `x^m`. Returns NaN if `coeff > m` or the estimation fails
+/// (see [`estimate_friedrich_coefficients`]).
+pub fn friedrich_coefficients(series: &[f64], coeff: usize, m: usize, r: usize) -> f64 {
+    estimate_friedrich_coefficients(series, m, r)
+        .and_then(|coefficients| coefficients.get(coeff).copied())
+        .unwrap_or(f64::NAN)
+}
+
+/// Returns the largest real part of the roots of a polynomial, or NaN if it has none.
+fn largest_fixed_point(coefficients: &[f64]) -> f64 {
+    if coefficients.iter().any(|c| !c.is_finite()) {
+        return f64::NAN;
+    }
+    polynomial_roots(coefficients)
+        .iter()
+        .map(|root| root.re)
+        .reduce(f64::max)
+        .unwrap_or(f64::NAN)
+}
+
+/// Calculates the largest fixed point of the deterministic dynamics, i.e. the
+/// largest real part of the roots of the Friedrich polynomial `h(x)` of degree
+/// `m` estimated with `r` quantile bins.
+///
+/// Returns NaN if the estimation fails or the polynomial has no roots.
+pub fn max_langevin_fixed_point(series: &[f64], m: usize, r: usize) -> f64 {
+    estimate_friedrich_coefficients(series, m, r)
+        .map_or(f64::NAN, |coefficients| largest_fixed_point(&coefficients))
+}
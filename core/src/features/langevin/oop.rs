@@ -0,0 +1,152 @@
+//! OOP API for Langevin model feature extraction.
+
+use std::collections::HashMap;
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{estimate_friedrich_coefficients, max_langevin_fixed_point};
+
+/// Feature function that returns coefficients of the Friedrich polynomial.
+///
+/// Each parameter is a `(coeff, m, r)` triple. The polynomial is estimated once
+/// per series for every distinct `(m, r)` pair.
+pub struct FriedrichCoefficients {
+    pub params: Vec<(usize, usize, usize)>,
+}
+
+impl FriedrichCoefficients {
+    pub fn new(params: Vec<(usize, usize, usize)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for FriedrichCoefficients {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut estimates = HashMap::new();
+        self.params
+            .iter()
+            .map(|&(coeff, m, r)| {
+                let coefficients = estimates
+                    .entry((m, r))
+                    .or_insert_with(|| estimate_friedrich_coefficients(series, m, r));
+                FeatureFunctionReturn {
+                    name: format_feature_name(
+                        "friedrich_coefficients",
+                        &[("coeff", &coeff), ("m", &m), ("r", &r)],
+                    ),
+                    value: coefficients
+                        .as_ref()
+                        .and_then(|coefficients| coefficients.get(coeff))
+                        .copied()
+                        .unwrap_or(f64::NAN),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Feature function that calculates the largest fixed point of the Langevin
+/// dynamics for polynomial degree `m` and `r` quantile bins.
+pub struct MaxLangevinFixedPoint {
+    pub m: usize,
+    pub r: usize,
+}
+
+impl MaxLangevinFixedPoint {
+    pub fn new(m: usize, r: usize) -> Self {
+        Self { m, r }
+    }
+}
+
+impl FeatureFunction for MaxLangevinFixedPoint {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name(
+                "max_langevin_fixed_point",
+                &[("m", &self.m), ("r", &self.r)],
+            ),
+            value: max_langevin_fixed_point(series, self.m, self.r),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    /// A noisy double-well process with stable points near ±1.
+    fn langevin_series() -> Vec<f64> {
+        let mut state: f64 = 0.3;
+        (0..200)
+            .map(|i| {
+                let t = i as f64;
+                state += 0.1 * (state - state.powi(3)) + 0.3 * (1.7 * t * t).sin();
+                state
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_friedrich_coefficients() {
+        let series = langevin_series();
+        let params = vec![(0, 3, 30), (1, 3, 30), (2, 3, 30), (3, 3, 30), (4, 3, 30)];
+        let result = FriedrichCoefficients::new(params).apply(&series);
+        assert_eq!(result[0].name, "friedrich_coefficients__coeff_0__m_3__r_30");
+        assert_float_eq!(result[0].value, -0.09408827954653372, 1e-12);
+        assert_float_eq!(result[1].value, -0.03170894257678431, 1e-12);
+        assert_float_eq!(result[2].value, 0.09913050856570069, 1e-12);
+        assert_float_eq!(result[3].value, 0.02895045800967803, 1e-12);
+        assert!(result[4].value.is_nan());
+
+        let params = vec![(0, 2, 10), (1, 2, 10), (2, 2, 10)];
+        let result = FriedrichCoefficients::new(params).apply(&series);
+        assert_float_eq!(result[0].value, -0.04399863450599653, 1e-12);
+        assert_float_eq!(result[1].value, -0.027315000690133047, 1e-12);
+        assert_float_eq!(result[2].value, 0.04759789792989245, 1e-12);
+    }
+
+    #[test]
+    fn test_friedrich_coefficients_degenerate() {
+        // Too few distinct values for distinct quantile bins.
+        let series = [1.0, 1.0, 1.0, 2.0, 1.0, 1.0];
+        let result = FriedrichCoefficients::new(vec![(0, 3, 30)]).apply(&series);
+        assert!(result[0].value.is_nan());
+
+        // Fewer bins than polynomial coefficients.
+        let series = [1.0, 2.0, 3.0, 4.0];
+        let result = FriedrichCoefficients::new(vec![(0, 3, 2)]).apply(&series);
+        assert!(result[0].value.is_nan());
+
+        let result = FriedrichCoefficients::new(vec![(0, 3, 30)]).apply(&[1.0]);
+        assert!(result[0].value.is_nan());
+
+        let mut series = langevin_series();
+        series[7] = f64::NAN;
+        let result = FriedrichCoefficients::new(vec![(0, 3, 30)]).apply(&series);
+        assert!(result[0].value.is_nan());
+        let result = MaxLangevinFixedPoint::new(3, 30).apply(&series);
+        assert!(result[0].value.is_nan());
+
+        series[7] = f64::INFINITY;
+        let result = FriedrichCoefficients::new(vec![(0, 3, 30)]).apply(&series);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_max_langevin_fixed_point() {
+        let series = langevin_series();
+        let result = MaxLangevinFixedPoint::new(3, 30).apply(&series);
+        assert_eq!(result[0].name, "max_langevin_fixed_point__m_3__r_30");
+        assert_float_eq!(result[0].value, 1.0091556789646943, 1e-10);
+
+        let result = MaxLangevinFixedPoint::new(2, 10).apply(&series);
+        assert_float_eq!(result[0].value, 0.77502190828782, 1e-10);
+
+        let result = MaxLangevinFixedPoint::new(5, 20).apply(&series);
+        assert_float_eq!(result[0].value, 3.069773854762776, 1e-9);
+
+        let result = MaxLangevinFixedPoint::new(3, 30).apply(&[2.0; 10]);
+        assert!(result[0].value.is_nan());
+    }
+}
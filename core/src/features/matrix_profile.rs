@@ -0,0 +1,16 @@
+//! Matrix profile feature extraction module.
+//!
+//! Provides a parallel matrix profile engine and features summarizing the
+//! profile, which highlight motifs (low values) and anomalies (high values),
+//! as well as MASS-based similarity search for user-supplied query patterns.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    MatrixProfileStatistic, compute_matrix_profile, mass, matrix_profile,
+    maximum_subsequence_window, query_hash, query_similarity_count,
+};
+pub use oop::{MatrixProfile, QuerySimilarityCount};
@@ -0,0 +1,367 @@
+//! Functional API for matrix profile feature extraction.
+
+use rayon::prelude::*;
+use rustfft::{FftPlanner, num_complex::Complex};
+
+use crate::features::{
+    common::FormatParam,
+    distribution::quantile_sorted,
+    minimal::{mean, median},
+};
+
+/// Smallest window considered by [`maximum_subsequence_window`].
+const MIN_WINDOW: usize = 8;
+
+/// Standard deviation below which stumpy treats a subsequence as constant.
+const STDDEV_THRESHOLD: f64 = 1e-7;
+
+/// Computes the matrix profile of a time series for subsequences of length `window`.
+///
+/// Entry `i` is the z-normalized Euclidean distance between the subsequence
+/// starting at `i` and its nearest neighbour, ignoring trivial matches that
+/// start within `ceil(window / 4)` values of `i`. Constant subsequences have
+/// distance 0 to each other and `sqrt(window)` to any other subsequence, like
+/// stumpy.
+///
+/// The profile is computed diagonal by diagonal with the O(1) covariance
+/// updates of STOMP, splitting the diagonals across the rayon thread pool.
+/// Entries without any non-trivial neighbour are infinite. Returns an empty
+/// profile if `window` is zero or larger than the series.
+pub fn compute_matrix_profile(series: &[f64], window: usize) -> Vec<f64> {
+    let n = series.len();
+    if window == 0 || window > n {
+        return Vec::new();
+    }
+    let profile_len = n - window + 1;
+    let exclusion = window.div_ceil(4);
+
+    let means: Vec<f64> = series.windows(window).map(mean).collect();
+    // Inverse norms of the centered subsequences, or 0 for constant ones.
+    let inv_norms: Vec<f64> = series
+        .windows(window)
+        .zip(&means)
+        .map(|(values, m)| {
+            let norm = values.iter().map(|x| (x - m).powi(2)).sum::<f64>().sqrt();
+            if norm > 0.0 { 1.0 / norm } else { 0.0 }
+        })
+        .collect();
+    // Update terms for moving from the covariance of (i - 1, j - 1) to (i, j).
+    let mut df = vec![0.0; profile_len];
+    let mut dg = vec![0.0; profile_len];
+    for k in 1..profile_len {
+        df[k] = (series[k + window - 1] - series[k - 1]) / 2.0;
+        dg[k] = (series[k + window - 1] - means[k]) + (series[k - 1] - means[k - 1]);
+    }
+
+    let correlations = (exclusion + 1..profile_len)
+        .into_par_iter()
+        .fold(
+            || vec![f64::NEG_INFINITY; profile_len],
+            |mut best, diagonal| {
+                let mut covariance: f64 = (0..window)
+                    .map(|t| (series[diagonal + t] - means[diagonal]) * (series[t] - means[0]))
+                    .sum();
+                for row in 0..profile_len - diagonal {
+                    let col = row + diagonal;
+                    covariance += df[row] * dg[col] + df[col] * dg[row];
+                    let correlation = match (inv_norms[row] == 0.0, inv_norms[col] == 0.0) {
+                        (true, true) => 1.0,
+                        // Corresponds to a distance of sqrt(window).
+                        (true, false) | (false, true) => 0.5,
+                        (false, false) => covariance * inv_norms[row] * inv_norms[col],
+                    };
+                    best[row] = best[row].max(correlation);
+                    best[col] = best[col].max(correlation);
+                }
+                best
+            },
+        )
+        .reduce(
+            || vec![f64::NEG_INFINITY; profile_len],
+            |mut a, b| {
+                for (x, y) in a.iter_mut().zip(b) {
+                    *x = x.max(y);
+                }
+                a
+            },
+        );
+
+    let scale = 2.0 * window as f64;
+    correlations
+        .into_iter()
+        .map(|correlation| {
+            if correlation == f64::NEG_INFINITY {
+                f64::INFINITY
+            } else {
+                (scale * (1.0 - correlation.min(1.0))).max(0.0).sqrt()
+            }
+        })
+        .collect()
+}
+
+/// Returns the highest Pearson correlation between any subsequence and its
+/// nearest neighbour, or NaN if no subsequence has a neighbour.
+fn max_correlation(series: &[f64], window: usize) -> f64 {
+    let scale = 2.0 * window as f64;
+    compute_matrix_profile(series, window)
+        .into_iter()
+        .filter(|d| d.is_finite())
+        .map(|d| 1.0 - d * d / scale)
+        .reduce(f64::max)
+        .unwrap_or(f64::NAN)
+}
+
+/// Finds the largest window whose best-matching pair of subsequences still has a
+/// Pearson correlation of at least `threshold`.
+///
+/// Follows the idea of matrixprofile's `maximum_subsequence`, which tsfresh
+/// uses when no window is given: starting at 8, the window is doubled while the
+/// threshold is met and then refined by bisection. Windows are limited to half
+/// the series length. If even the smallest window misses the threshold, it is
+/// returned anyway. Returns `None` for series shorter than 16 values.
+pub fn maximum_subsequence_window(series: &[f64], threshold: f64) -> Option<usize> {
+    let max_window = series.len() / 2;
+    if max_window < MIN_WINDOW {
+        return None;
+    }
+    let meets_threshold = |window: usize| max_correlation(series, window) >= threshold;
+    let mut good = None;
+    let mut bad = max_window + 1;
+    let mut window = MIN_WINDOW;
+    while window <= max_window {
+        if !meets_threshold(window) {
+            bad = window;
+            break;
+        }
+        good = Some(window);
+        window *= 2;
+    }
+    let Some(mut good) = good else {
+        return Some(MIN_WINDOW);
+    };
+    while bad - good > 1 {
+        let middle = good + (bad - good) / 2;
+        if meets_threshold(middle) {
+            good = middle;
+        } else {
+            bad = middle;
+        }
+    }
+    Some(good)
+}
+
+/// Summary statistic of a matrix profile reported by [`matrix_profile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixProfileStatistic {
+    Min,
+    Max,
+    Mean,
+    Median,
+    /// 25th percentile.
+    Percentile25,
+    /// 75th percentile.
+    Percentile75,
+}
+
+impl MatrixProfileStatistic {
+    /// Returns the tsfresh name of the statistic.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatrixProfileStatistic::Min => "min",
+            MatrixProfileStatistic::Max => "max",
+            MatrixProfileStatistic::Mean => "mean",
+            MatrixProfileStatistic::Median => "median",
+            MatrixProfileStatistic::Percentile25 => "25",
+            MatrixProfileStatistic::Percentile75 => "75",
+        }
+    }
+
+    /// Computes the statistic over the finite entries of a profile, or NaN if
+    /// there are none.
+    pub(crate) fn summarize(&self, profile: &[f64]) -> f64 {
+        let mut finite: Vec<f64> = profile.iter().copied().filter(|d| d.is_finite()).collect();
+        if finite.is_empty() {
+            return f64::NAN;
+        }
+        match self {
+            MatrixProfileStatistic::Min => finite.iter().copied().fold(f64::INFINITY, f64::min),
+            MatrixProfileStatistic::Max => finite.iter().copied().fold(0.0, f64::max),
+            MatrixProfileStatistic::Mean => mean(&finite),
+            MatrixProfileStatistic::Median => median(&finite),
+            MatrixProfileStatistic::Percentile25 | MatrixProfileStatistic::Percentile75 => {
+                finite.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let q = if *self == MatrixProfileStatistic::Percentile25 {
+                    0.25
+                } else {
+                    0.75
+                };
+                quantile_sorted(&finite, q)
+            }
+        }
+    }
+}
+
+impl FormatParam for MatrixProfileStatistic {
+    fn format_param(&self) -> String {
+        self.as_str().format_param()
+    }
+}
+
+/// Computes the matrix profile used by the features: for the given window, or
+/// for the window chosen by [`maximum_subsequence_window`] with `threshold`.
+pub(crate) fn feature_profile(series: &[f64], windows: Option<usize>, threshold: f64) -> Vec<f64> {
+    match windows.or_else(|| maximum_subsequence_window(series, threshold)) {
+        Some(window) => compute_matrix_profile(series, window),
+        None => Vec::new(),
+    }
+}
+
+/// Calculates a summary statistic of the matrix profile of a time series.
+///
+/// With `windows` set, the profile uses that subsequence length and
+/// `threshold` is unused. Otherwise the window is chosen by
+/// [`maximum_subsequence_window`] with `threshold`, tsfresh's default being
+/// 0.98. Infinite profile entries are ignored; returns NaN if no finite entry
+/// remains.
+pub fn matrix_profile(
+    series: &[f64],
+    windows: Option<usize>,
+    feature: MatrixProfileStatistic,
+    threshold: f64,
+) -> f64 {
+    feature.summarize(&feature_profile(series, windows, threshold))
+}
+
+/// Calculates `sum(query[t] * series[i + t])` for every subsequence start `i`
+/// via FFT convolution.
+fn sliding_dot_product(query: &[f64], series: &[f64]) -> Vec<f64> {
+    let (m, n) = (query.len(), series.len());
+    let size = (n + m - 1).next_power_of_two();
+    let mut reversed: Vec<Complex<f64>> =
+        query.iter().rev().map(|&x| Complex::new(x, 0.0)).collect();
+    reversed.resize(size, Complex::new(0.0, 0.0));
+    let mut buffer: Vec<Complex<f64>> = series.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    buffer.resize(size, Complex::new(0.0, 0.0));
+    let mut planner = FftPlanner::new();
+    let forward = planner.plan_fft_forward(size);
+    forward.process(&mut reversed);
+    forward.process(&mut buffer);
+    for (b, q) in buffer.iter_mut().zip(&reversed) {
+        *b *= q;
+    }
+    planner.plan_fft_inverse(size).process(&mut buffer);
+    buffer[m - 1..n]
+        .iter()
+        .map(|c| c.re / size as f64)
+        .collect()
+}
+
+/// Computes the distance profile of `query` against every subsequence of
+/// `series` with the MASS algorithm, like `stumpy.core.mass`.
+///
+/// With `normalize`, distances are z-normalized Euclidean distances: two
+/// constant sequences have distance 0 and a constant and a non-constant one
+/// have distance `sqrt(m)`. Otherwise plain Euclidean distances are returned,
+/// like `stumpy.core.mass_absolute`. Returns an empty profile if the query is
+/// empty or longer than the series.
+pub fn mass(query: &[f64], series: &[f64], normalize: bool) -> Vec<f64> {
+    let (m, n) = (query.len(), series.len());
+    if m == 0 || m > n {
+        return Vec::new();
+    }
+    let dot_products = sliding_dot_product(query, series);
+    let mf = m as f64;
+
+    if !normalize {
+        let query_energy: f64 = query.iter().map(|q| q * q).sum();
+        let mut window_energy: f64 = series[..m].iter().map(|x| x * x).sum();
+        return dot_products
+            .iter()
+            .enumerate()
+            .map(|(i, qt)| {
+                if i > 0 {
+                    window_energy += series[i + m - 1].powi(2) - series[i - 1].powi(2);
+                }
+                (query_energy + window_energy - 2.0 * qt).abs().sqrt()
+            })
+            .collect();
+    }
+
+    let query_mean = mean(query);
+    let query_std = (query.iter().map(|q| (q - query_mean).powi(2)).sum::<f64>() / mf).sqrt();
+    let query_constant = query_std < STDDEV_THRESHOLD;
+    // Moments are accumulated relative to the series mean to limit cancellation.
+    let shift = mean(series);
+    let mut prefix_sum = vec![0.0; n + 1];
+    let mut prefix_squares = vec![0.0; n + 1];
+    for (i, x) in series.iter().enumerate() {
+        prefix_sum[i + 1] = prefix_sum[i] + (x - shift);
+        prefix_squares[i + 1] = prefix_squares[i] + (x - shift).powi(2);
+    }
+    // Start of the run of equal values that each position belongs to.
+    let mut run_start = vec![0; n];
+    for i in 1..n {
+        run_start[i] = if series[i] == series[i - 1] {
+            run_start[i - 1]
+        } else {
+            i
+        };
+    }
+
+    dot_products
+        .iter()
+        .enumerate()
+        .map(|(i, qt)| {
+            let centered_mean = (prefix_sum[i + m] - prefix_sum[i]) / mf;
+            let variance = (prefix_squares[i + m] - prefix_squares[i]) / mf - centered_mean.powi(2);
+            let window_std = variance.max(0.0).sqrt();
+            let window_constant = run_start[i + m - 1] <= i || window_std < STDDEV_THRESHOLD;
+            match (query_constant, window_constant) {
+                (true, true) => 0.0,
+                (true, false) | (false, true) => mf.sqrt(),
+                (false, false) => {
+                    let window_mean = centered_mean + shift;
+                    let correlation =
+                        (qt - mf * query_mean * window_mean) / (mf * query_std * window_std);
+                    (2.0 * mf * (1.0 - correlation.min(1.0))).abs().sqrt()
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns a stable 64-bit FNV-1a hash of a query, rendered as 16 hex digits.
+///
+/// Query similarity features use it in their names instead of the full query,
+/// so the same query always yields the same name across runs and platforms.
+pub fn query_hash(query: &[f64]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for value in query {
+        for byte in value.to_bits().to_le_bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+/// Counts the subsequences of a time series whose distance to `query` is at
+/// most `threshold`.
+///
+/// Distances come from [`mass`], z-normalized if `normalize` is set. A
+/// threshold of 0 counts exact matches only. Returns NaN if the query has fewer
+/// than 3 values, like tsfresh, or is longer than the series.
+pub fn query_similarity_count(
+    series: &[f64],
+    query: &[f64],
+    threshold: f64,
+    normalize: bool,
+) -> f64 {
+    if query.len() < 3 || query.len() > series.len() {
+        return f64::NAN;
+    }
+    mass(query, series, normalize)
+        .into_iter()
+        .filter(|&d| d <= threshold)
+        .count() as f64
+}
@@ -0,0 +1,327 @@
+//! OOP API for matrix profile feature extraction.
+
+use std::collections::HashMap;
+
+use crate::features::common::{
+    FeatureFunction, FeatureFunctionReturn, FormatParam, Number, format_feature_name,
+};
+
+use super::functional::{
+    MatrixProfileStatistic, feature_profile, query_hash, query_similarity_count,
+};
+
+/// Feature function that reports summary statistics of the matrix profile.
+///
+/// Each parameter is a `(windows, feature, threshold)` triple. The profile is
+/// computed once per series for every distinct `(windows, threshold)` pair.
+/// Without a threshold, [`DEFAULT_THRESHOLD`](Self::DEFAULT_THRESHOLD) is used.
+/// `windows` and `threshold` only appear in the feature name when they are set,
+/// like in tsfresh.
+pub struct MatrixProfile {
+    pub params: Vec<(Option<usize>, MatrixProfileStatistic, Option<Number>)>,
+}
+
+impl MatrixProfile {
+    /// The correlation threshold tsfresh uses when none is given.
+    pub const DEFAULT_THRESHOLD: f64 = 0.98;
+
+    pub fn new(params: Vec<(Option<usize>, MatrixProfileStatistic, Option<Number>)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for MatrixProfile {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut profiles = HashMap::new();
+        self.params
+            .iter()
+            .map(|(windows, feature, threshold)| {
+                let value = threshold.map_or(Self::DEFAULT_THRESHOLD, Number::as_f64);
+                let profile = profiles
+                    .entry((*windows, value.to_bits()))
+                    .or_insert_with(|| feature_profile(series, *windows, value));
+                let mut params: Vec<(&str, &dyn FormatParam)> = vec![("feature", feature)];
+                if let Some(threshold) = threshold {
+                    params.push(("threshold", threshold));
+                }
+                if let Some(windows) = windows {
+                    params.push(("windows", windows));
+                }
+                FeatureFunctionReturn {
+                    name: format_feature_name("matrix_profile", &params),
+                    value: feature.summarize(profile),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Feature function that counts the subsequences similar to user-supplied queries.
+///
+/// Each parameter is a `(query, threshold, normalize)` triple owning its query.
+/// The query appears in the feature name as its [`query_hash`]. Like in
+/// tsfresh, `threshold` defaults to 0 and `normalize` to `true`, and both only
+/// appear in the feature name when they are set.
+pub struct QuerySimilarityCount {
+    pub params: Vec<(Vec<f64>, Option<Number>, Option<bool>)>,
+}
+
+impl QuerySimilarityCount {
+    pub fn new(params: Vec<(Vec<f64>, Option<Number>, Option<bool>)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for QuerySimilarityCount {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        self.params
+            .iter()
+            .map(|(query, threshold, normalize)| {
+                let hash = query_hash(query);
+                let mut params: Vec<(&str, &dyn FormatParam)> = vec![("query", &hash)];
+                if let Some(threshold) = threshold {
+                    params.push(("threshold", threshold));
+                }
+                if let Some(normalize) = normalize {
+                    params.push(("normalize", normalize));
+                }
+                FeatureFunctionReturn {
+                    name: format_feature_name("query_similarity_count", &params),
+                    value: query_similarity_count(
+                        series,
+                        query,
+                        threshold.map_or(0.0, Number::as_f64),
+                        normalize.unwrap_or(true),
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_float_eq,
+        features::matrix_profile::{
+            compute_matrix_profile, mass, maximum_subsequence_window, query_hash,
+        },
+        test_utils::synthetic_series,
+    };
+
+    const ALL_STATISTICS: [MatrixProfileStatistic; 6] = [
+        MatrixProfileStatistic::Min,
+        MatrixProfileStatistic::Max,
+        MatrixProfileStatistic::Mean,
+        MatrixProfileStatistic::Median,
+        MatrixProfileStatistic::Percentile25,
+        MatrixProfileStatistic::Percentile75,
+    ];
+
+    fn all_statistics(windows: Option<usize>, threshold: Option<f64>) -> MatrixProfile {
+        MatrixProfile::new(
+            ALL_STATISTICS
+                .iter()
+                .map(|&feature| (windows, feature, threshold.map(Number::from)))
+                .collect(),
+        )
+    }
+
+    fn assert_statistics(result: &[FeatureFunctionReturn], expected: [f64; 6]) {
+        for (feature, expected) in result.iter().zip(expected) {
+            assert_float_eq!(feature.value, expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_compute_matrix_profile_matches_brute_force() {
+        let series = synthetic_series(80);
+        let window = 7;
+        let profile = compute_matrix_profile(&series, window);
+        let windows: Vec<Vec<f64>> = series
+            .windows(window)
+            .map(|w| {
+                let m = w.iter().sum::<f64>() / window as f64;
+                let sd = (w.iter().map(|x| (x - m).powi(2)).sum::<f64>() / window as f64).sqrt();
+                w.iter().map(|x| (x - m) / sd).collect()
+            })
+            .collect();
+        for (i, &distance) in profile.iter().enumerate() {
+            let expected = (0..windows.len())
+                .filter(|&j| i.abs_diff(j) > 2)
+                .map(|j| {
+                    windows[i]
+                        .iter()
+                        .zip(&windows[j])
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert_float_eq!(distance, expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_compute_matrix_profile_edge_cases() {
+        assert!(compute_matrix_profile(&[1.0, 2.0], 3).is_empty());
+        assert!(compute_matrix_profile(&[1.0, 2.0], 0).is_empty());
+        // No subsequence has a neighbour outside the exclusion zone.
+        let profile = compute_matrix_profile(&[1.0, 2.0, 4.0, 3.0], 4);
+        assert_eq!(profile, vec![f64::INFINITY]);
+        // Constant subsequences match each other exactly and others at sqrt(window).
+        let profile = compute_matrix_profile(&[1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 5.0, 2.0], 2);
+        assert_float_eq!(profile[0], 0.0);
+        assert_float_eq!(profile[4], 0.0);
+        assert_float_eq!(profile[5], 2f64.sqrt());
+        assert_float_eq!(profile[6], 2f64.sqrt());
+    }
+
+    #[test]
+    fn test_matrix_profile_fixed_window() {
+        let series = synthetic_series(80);
+        let result = all_statistics(Some(5), Some(0.98)).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "matrix_profile__feature_\"min\"__threshold_0.98__windows_5"
+        );
+        assert_eq!(
+            result[4].name,
+            "matrix_profile__feature_\"25\"__threshold_0.98__windows_5"
+        );
+        assert_statistics(
+            &result,
+            [
+                0.12802781703751226,
+                1.3437955242000987,
+                0.658347453061015,
+                0.6173035296733984,
+                0.47073810189542237,
+                0.8220673897764632,
+            ],
+        );
+
+        let result = all_statistics(Some(12), None).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "matrix_profile__feature_\"min\"__windows_12"
+        );
+        assert_statistics(
+            &result,
+            [
+                0.5503720297821943,
+                2.7756625489284374,
+                1.4202691898027775,
+                1.3590304298934937,
+                1.02111889691692,
+                1.8456397148146033,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_matrix_profile_window_search() {
+        let series = synthetic_series(80);
+        assert_eq!(maximum_subsequence_window(&series, 0.98), Some(17));
+        assert_eq!(maximum_subsequence_window(&series, 0.9), Some(40));
+
+        let result = all_statistics(None, Some(0.9)).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "matrix_profile__feature_\"min\"__threshold_0.9"
+        );
+        assert_statistics(
+            &result,
+            [
+                2.374960507923293,
+                3.456292443061922,
+                2.695732253176133,
+                2.5432839182736022,
+                2.505574892919441,
+                2.8180398236574753,
+            ],
+        );
+
+        let sine: Vec<f64> = (0..60).map(|i| (0.5 * i as f64).sin()).collect();
+        assert_eq!(maximum_subsequence_window(&sine, 0.98), Some(30));
+        let result = all_statistics(None, None).apply(&sine);
+        assert_float_eq!(result[1].value, 1.2188847850411528, 1e-9);
+
+        let result = all_statistics(None, None).apply(&series[..15]);
+        assert!(result.iter().all(|r| r.value.is_nan()));
+    }
+
+    fn test_query() -> Vec<f64> {
+        (0..10).map(|t| (0.3 * t as f64).sin()).collect()
+    }
+
+    #[test]
+    fn test_mass() {
+        let series = synthetic_series(80);
+        let query = test_query();
+        let normalized = mass(&query, &series, true);
+        assert_eq!(normalized.len(), 71);
+        assert_float_eq!(normalized[0], 3.431429210782862, 1e-9);
+        assert_float_eq!(normalized[30], 5.962668878771085, 1e-9);
+        assert_float_eq!(normalized[70], 5.397185562661907, 1e-9);
+
+        let absolute = mass(&query, &series, false);
+        assert_float_eq!(absolute[0], 0.9573589717341812, 1e-9);
+        assert_float_eq!(absolute[30], 3.863495225992087, 1e-9);
+        assert_float_eq!(absolute[70], 2.6213576654717046, 1e-9);
+
+        assert!(mass(&query, &series[..9], true).is_empty());
+        let constant = mass(&[2.0; 3], &[1.0, 1.0, 1.0, 1.0, 3.0], true);
+        assert_float_eq!(constant[0], 0.0);
+        assert_float_eq!(constant[1], 0.0);
+        assert_float_eq!(constant[2], 3f64.sqrt());
+    }
+
+    #[test]
+    fn test_query_hash() {
+        assert_eq!(query_hash(&test_query()), "f87569445ca3cb79");
+        assert_eq!(query_hash(&[1.0, 2.0, 3.0]), "e2d5ae79fc4e9a70");
+    }
+
+    #[test]
+    fn test_query_similarity_count() {
+        let series = synthetic_series(80);
+        let feature = QuerySimilarityCount::new(vec![
+            (test_query(), Some(2.0.into()), Some(true)),
+            (test_query(), Some(3.into()), None),
+            (test_query(), Some(1.0.into()), Some(false)),
+            (test_query(), Some(3.0.into()), Some(false)),
+            (series[20..32].to_vec(), Some(1e-6.into()), Some(true)),
+            (vec![1.0, 2.0], Some(10.0.into()), Some(true)),
+            (series[20..32].to_vec(), None, None),
+        ]);
+        let result = feature.apply(&series);
+        assert_eq!(
+            result[0].name,
+            "query_similarity_count__normalize_True__query_\"f87569445ca3cb79\"__threshold_2.0"
+        );
+        assert_eq!(
+            result[1].name,
+            "query_similarity_count__query_\"f87569445ca3cb79\"__threshold_3"
+        );
+        assert_eq!(
+            result[2].name,
+            "query_similarity_count__normalize_False__query_\"f87569445ca3cb79\"__threshold_1.0"
+        );
+        assert_eq!(result[0].value, 0.0);
+        assert_eq!(result[1].value, 15.0);
+        assert_eq!(result[2].value, 2.0);
+        assert_eq!(result[3].value, 47.0);
+        assert_eq!(result[4].value, 1.0);
+        assert!(result[5].value.is_nan());
+        assert_eq!(
+            result[6].name,
+            format!(
+                "query_similarity_count__query_\"{}\"",
+                query_hash(&series[20..32])
+            )
+        );
+        assert_eq!(result[6].value, 1.0);
+    }
+}
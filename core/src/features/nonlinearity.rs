@@ -0,0 +1,11 @@
+//! Nonlinearity feature extraction module.
+//!
+//! Provides lagged higher-order statistics that detect nonlinear dynamics.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{c3, time_reversal_asymmetry_statistic};
+pub use oop::{C3, TimeReversalAsymmetryStatistic};
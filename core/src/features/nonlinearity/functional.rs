@@ -0,0 +1,46 @@
+//! Functional API for nonlinearity feature extraction.
+
+/// Averages `f(x[i], x[i + lag], x[i + 2 * lag])` over all valid `i`.
+///
+/// Returns NaN if `2 * lag >= n`, i.e. if there is no such triple. tsfresh
+/// returns 0 in that case, which is indistinguishable from a genuine zero.
+fn mean_of_lagged_triples(series: &[f64], lag: usize, f: impl Fn(f64, f64, f64) -> f64) -> f64 {
+    let n = series.len();
+    let Some(count) = lag.checked_mul(2).and_then(|span| n.checked_sub(span)) else {
+        return f64::NAN;
+    };
+    if count == 0 {
+        return f64::NAN;
+    }
+    let sum: f64 = (0..count)
+        .map(|i| f(series[i], series[i + lag], series[i + 2 * lag]))
+        .sum();
+    sum / count as f64
+}
+
+/// Calculates the c3 nonlinearity statistic `mean(x[i] * x[i + lag] * x[i + 2 * lag])`.
+///
+/// Returns NaN if `2 * lag >= n`.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::nonlinearity::c3;
+///
+/// let series = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// assert_eq!(c3(&series, 1), 30.0);
+/// assert!(c3(&series, 3).is_nan());
+/// ```
+pub fn c3(series: &[f64], lag: usize) -> f64 {
+    mean_of_lagged_triples(series, lag, |x, one_lag, two_lag| two_lag * one_lag * x)
+}
+
+/// Calculates the time reversal asymmetry statistic
+/// `mean(x[i + 2 * lag]² * x[i + lag] - x[i + lag] * x[i]²)`.
+///
+/// Returns NaN if `2 * lag >= n`.
+pub fn time_reversal_asymmetry_statistic(series: &[f64], lag: usize) -> f64 {
+    mean_of_lagged_triples(series, lag, |x, one_lag, two_lag| {
+        two_lag * two_lag * one_lag - one_lag * x * x
+    })
+}
@@ -0,0 +1,100 @@
+//! OOP API for nonlinearity feature extraction.
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{c3, time_reversal_asymmetry_statistic};
+
+/// Feature function that calculates the c3 nonlinearity statistic at a given lag.
+pub struct C3 {
+    pub lag: usize,
+}
+
+impl C3 {
+    pub fn new(lag: usize) -> Self {
+        Self { lag }
+    }
+}
+
+impl FeatureFunction for C3 {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("c3", &[("lag", &self.lag)]),
+            value: c3(series, self.lag),
+        }]
+    }
+}
+
+/// Feature function that calculates the time reversal asymmetry statistic at a given lag.
+pub struct TimeReversalAsymmetryStatistic {
+    pub lag: usize,
+}
+
+impl TimeReversalAsymmetryStatistic {
+    pub fn new(lag: usize) -> Self {
+        Self { lag }
+    }
+}
+
+impl FeatureFunction for TimeReversalAsymmetryStatistic {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("time_reversal_asymmetry_statistic", &[("lag", &self.lag)]),
+            value: time_reversal_asymmetry_statistic(series, self.lag),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_float_eq, test_utils::synthetic_series};
+
+    #[test]
+    fn test_c3() {
+        let series = synthetic_series(60);
+        let result = C3::new(1).apply(&series);
+        assert_eq!(result[0].name, "c3__lag_1");
+        assert_float_eq!(result[0].value, 0.191656737341534);
+
+        let cases = [
+            (2, 0.17204312421103812),
+            (3, 0.12683481213859726),
+            (29, -0.03871008534558001),
+        ];
+        for (lag, expected) in cases {
+            assert_float_eq!(C3::new(lag).apply(&series)[0].value, expected);
+        }
+
+        let series = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_float_eq!(C3::new(2).apply(&series)[0].value, 15.0);
+        assert!(C3::new(3).apply(&series)[0].value.is_nan());
+        assert!(C3::new(usize::MAX).apply(&series)[0].value.is_nan());
+        assert!(C3::new(0).apply(&[])[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_time_reversal_asymmetry_statistic() {
+        let series = synthetic_series(60);
+        let result = TimeReversalAsymmetryStatistic::new(1).apply(&series);
+        assert_eq!(result[0].name, "time_reversal_asymmetry_statistic__lag_1");
+        assert_float_eq!(result[0].value, -0.0047649358250088794);
+
+        let cases = [
+            (2, 0.007954607094143737),
+            (3, -0.03133789068239903),
+            (29, -0.08695479400611755),
+        ];
+        for (lag, expected) in cases {
+            let result = TimeReversalAsymmetryStatistic::new(lag).apply(&series);
+            assert_float_eq!(result[0].value, expected);
+        }
+
+        let series = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = TimeReversalAsymmetryStatistic::new(1).apply(&series);
+        assert_float_eq!(result[0].value, 38.666666666666664);
+        let result = TimeReversalAsymmetryStatistic::new(2).apply(&series);
+        assert_float_eq!(result[0].value, 72.0);
+        let result = TimeReversalAsymmetryStatistic::new(3).apply(&series);
+        assert!(result[0].value.is_nan());
+    }
+}
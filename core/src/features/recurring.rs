@@ -0,0 +1,20 @@
+//! Recurring value feature extraction module.
+//!
+//! Provides features describing how often values reoccur in a time series.
+//! All features are derived from a single hash-based value-counting pass.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    ValueCounts, percentage_of_reoccurring_datapoints_to_all_datapoints,
+    percentage_of_reoccurring_values_to_all_values, ratio_value_number_to_time_series_length,
+    sum_of_reoccurring_data_points, sum_of_reoccurring_values,
+};
+pub use oop::{
+    PercentageOfReoccurringDatapointsToAllDatapoints, PercentageOfReoccurringValuesToAllValues,
+    RatioValueNumberToTimeSeriesLength, RecurringFeatureSet, SumOfReoccurringDataPoints,
+    SumOfReoccurringValues,
+};
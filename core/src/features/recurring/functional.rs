@@ -0,0 +1,118 @@
+//! Functional API for recurring value feature extraction.
+
+use std::collections::HashMap;
+
+/// Occurrence counts of the distinct values of a time series.
+///
+/// Values are hashed by their bit pattern, with `-0.0` folded into `0.0` and all
+/// NaNs folded into one value, so counting is a single O(n) pass.
+pub struct ValueCounts {
+    counts: HashMap<u64, (f64, usize)>,
+    length: usize,
+}
+
+impl ValueCounts {
+    /// Counts the occurrences of every distinct value in `series`.
+    pub fn new(series: &[f64]) -> Self {
+        let mut counts: HashMap<u64, (f64, usize)> = HashMap::with_capacity(series.len());
+        for &x in series {
+            let key = if x.is_nan() {
+                f64::NAN.to_bits()
+            } else {
+                (x + 0.0).to_bits()
+            };
+            counts.entry(key).or_insert((x, 0)).1 += 1;
+        }
+        Self {
+            counts,
+            length: series.len(),
+        }
+    }
+
+    /// Iterates over the values that occur more than once, with their counts.
+    fn reoccurring(&self) -> impl Iterator<Item = (f64, usize)> + '_ {
+        self.counts
+            .values()
+            .copied()
+            .filter(|&(_, count)| count > 1)
+    }
+
+    /// Fraction of distinct values that occur more than once.
+    pub fn percentage_of_reoccurring_values_to_all_values(&self) -> f64 {
+        if self.length == 0 {
+            return f64::NAN;
+        }
+        self.reoccurring().count() as f64 / self.counts.len() as f64
+    }
+
+    /// Fraction of data points whose value occurs more than once.
+    pub fn percentage_of_reoccurring_datapoints_to_all_datapoints(&self) -> f64 {
+        if self.length == 0 {
+            return f64::NAN;
+        }
+        self.reoccurring().map(|(_, count)| count).sum::<usize>() as f64 / self.length as f64
+    }
+
+    /// Values that occur more than once in ascending order, so sums do not
+    /// depend on the hash map iteration order.
+    fn sorted_reoccurring(&self) -> Vec<(f64, usize)> {
+        let mut reoccurring: Vec<(f64, usize)> = self.reoccurring().collect();
+        reoccurring.sort_by(|a, b| a.0.total_cmp(&b.0));
+        reoccurring
+    }
+
+    /// Sum of the distinct values that occur more than once, each counted once.
+    pub fn sum_of_reoccurring_values(&self) -> f64 {
+        self.sorted_reoccurring()
+            .into_iter()
+            .map(|(value, _)| value)
+            .sum()
+    }
+
+    /// Sum of all data points whose value occurs more than once.
+    pub fn sum_of_reoccurring_data_points(&self) -> f64 {
+        self.sorted_reoccurring()
+            .into_iter()
+            .map(|(value, count)| value * count as f64)
+            .sum()
+    }
+
+    /// Number of distinct values divided by the length of the series.
+    pub fn ratio_value_number_to_time_series_length(&self) -> f64 {
+        if self.length == 0 {
+            return f64::NAN;
+        }
+        self.counts.len() as f64 / self.length as f64
+    }
+}
+
+/// Calculates the fraction of distinct values that occur more than once.
+///
+/// Returns NaN for an empty series.
+pub fn percentage_of_reoccurring_values_to_all_values(series: &[f64]) -> f64 {
+    ValueCounts::new(series).percentage_of_reoccurring_values_to_all_values()
+}
+
+/// Calculates the fraction of data points whose value occurs more than once.
+///
+/// Returns NaN for an empty series.
+pub fn percentage_of_reoccurring_datapoints_to_all_datapoints(series: &[f64]) -> f64 {
+    ValueCounts::new(series).percentage_of_reoccurring_datapoints_to_all_datapoints()
+}
+
+/// Calculates the sum of the distinct values that occur more than once.
+pub fn sum_of_reoccurring_values(series: &[f64]) -> f64 {
+    ValueCounts::new(series).sum_of_reoccurring_values()
+}
+
+/// Calculates the sum of all data points whose value occurs more than once.
+pub fn sum_of_reoccurring_data_points(series: &[f64]) -> f64 {
+    ValueCounts::new(series).sum_of_reoccurring_data_points()
+}
+
+/// Calculates the number of distinct values divided by the length of a time series.
+///
+/// Returns NaN for an empty series.
+pub fn ratio_value_number_to_time_series_length(series: &[f64]) -> f64 {
+    ValueCounts::new(series).ratio_value_number_to_time_series_length()
+}
@@ -0,0 +1,230 @@
+//! OOP API for recurring value feature extraction.
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn};
+
+use super::functional::{
+    ValueCounts, percentage_of_reoccurring_datapoints_to_all_datapoints,
+    percentage_of_reoccurring_values_to_all_values, ratio_value_number_to_time_series_length,
+    sum_of_reoccurring_data_points, sum_of_reoccurring_values,
+};
+
+/// Feature function that calculates the fraction of distinct values that occur more than once.
+#[derive(Default)]
+pub struct PercentageOfReoccurringValuesToAllValues;
+
+impl PercentageOfReoccurringValuesToAllValues {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for PercentageOfReoccurringValuesToAllValues {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "percentage_of_reoccurring_values_to_all_values".to_string(),
+            value: percentage_of_reoccurring_values_to_all_values(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the fraction of data points whose value occurs more than once.
+#[derive(Default)]
+pub struct PercentageOfReoccurringDatapointsToAllDatapoints;
+
+impl PercentageOfReoccurringDatapointsToAllDatapoints {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for PercentageOfReoccurringDatapointsToAllDatapoints {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "percentage_of_reoccurring_datapoints_to_all_datapoints".to_string(),
+            value: percentage_of_reoccurring_datapoints_to_all_datapoints(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the sum of the distinct values that occur more than once.
+#[derive(Default)]
+pub struct SumOfReoccurringValues;
+
+impl SumOfReoccurringValues {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for SumOfReoccurringValues {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "sum_of_reoccurring_values".to_string(),
+            value: sum_of_reoccurring_values(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the sum of the data points whose value occurs more than once.
+#[derive(Default)]
+pub struct SumOfReoccurringDataPoints;
+
+impl SumOfReoccurringDataPoints {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for SumOfReoccurringDataPoints {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "sum_of_reoccurring_data_points".to_string(),
+            value: sum_of_reoccurring_data_points(series),
+        }]
+    }
+}
+
+/// Feature function that calculates the number of distinct values relative to the series length.
+#[derive(Default)]
+pub struct RatioValueNumberToTimeSeriesLength;
+
+impl RatioValueNumberToTimeSeriesLength {
+    pub const DEFAULT: Self = Self;
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FeatureFunction for RatioValueNumberToTimeSeriesLength {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: "ratio_value_number_to_time_series_length".to_string(),
+            value: ratio_value_number_to_time_series_length(series),
+        }]
+    }
+}
+
+/// Computes all recurring value features from a single value-counting pass.
+#[derive(Default)]
+pub struct RecurringFeatureSet {}
+
+impl RecurringFeatureSet {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl FeatureFunction for RecurringFeatureSet {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let counts = ValueCounts::new(series);
+        vec![
+            FeatureFunctionReturn {
+                name: "percentage_of_reoccurring_values_to_all_values".to_string(),
+                value: counts.percentage_of_reoccurring_values_to_all_values(),
+            },
+            FeatureFunctionReturn {
+                name: "percentage_of_reoccurring_datapoints_to_all_datapoints".to_string(),
+                value: counts.percentage_of_reoccurring_datapoints_to_all_datapoints(),
+            },
+            FeatureFunctionReturn {
+                name: "sum_of_reoccurring_values".to_string(),
+                value: counts.sum_of_reoccurring_values(),
+            },
+            FeatureFunctionReturn {
+                name: "sum_of_reoccurring_data_points".to_string(),
+                value: counts.sum_of_reoccurring_data_points(),
+            },
+            FeatureFunctionReturn {
+                name: "ratio_value_number_to_time_series_length".to_string(),
+                value: counts.ratio_value_number_to_time_series_length(),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_percentage_of_reoccurring_values_to_all_values() {
+        let series = [1.0, 1.0, 2.0, 3.0, 4.0];
+        let result = PercentageOfReoccurringValuesToAllValues::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.25);
+
+        let empty: [f64; 0] = [];
+        let result = PercentageOfReoccurringValuesToAllValues::new().apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_percentage_of_reoccurring_datapoints_to_all_datapoints() {
+        let series = [1.0, 1.0, 2.0, 3.0, 4.0, 4.0, 4.0, 5.0];
+        let result = PercentageOfReoccurringDatapointsToAllDatapoints::new().apply(&series);
+        assert_float_eq!(result[0].value, 5.0 / 8.0);
+
+        let unique = [1.0, 2.0, 3.0];
+        let result = PercentageOfReoccurringDatapointsToAllDatapoints::new().apply(&unique);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_sum_of_reoccurring_values() {
+        let series = [2.0, 2.0, 2.0, 2.0, 1.0];
+        let result = SumOfReoccurringValues::new().apply(&series);
+        assert_float_eq!(result[0].value, 2.0);
+
+        let signed_zero = [0.0, -0.0, 3.0, 3.0];
+        let result = SumOfReoccurringValues::new().apply(&signed_zero);
+        assert_float_eq!(result[0].value, 3.0);
+    }
+
+    #[test]
+    fn test_sum_of_reoccurring_data_points() {
+        let series = [2.0, 2.0, 2.0, 2.0, 1.0];
+        let result = SumOfReoccurringDataPoints::new().apply(&series);
+        assert_float_eq!(result[0].value, 8.0);
+
+        let empty: [f64; 0] = [];
+        let result = SumOfReoccurringDataPoints::new().apply(&empty);
+        assert_float_eq!(result[0].value, 0.0);
+    }
+
+    #[test]
+    fn test_ratio_value_number_to_time_series_length() {
+        let series = [1.0, 1.0, 2.0, 3.0];
+        let result = RatioValueNumberToTimeSeriesLength::new().apply(&series);
+        assert_float_eq!(result[0].value, 0.75);
+
+        let signed_zero = [0.0, -0.0];
+        let result = RatioValueNumberToTimeSeriesLength::new().apply(&signed_zero);
+        assert_float_eq!(result[0].value, 0.5);
+
+        let empty: [f64; 0] = [];
+        let result = RatioValueNumberToTimeSeriesLength::new().apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_recurring_feature_set() {
+        let series = [1.0, 1.0, 2.0, 3.0, 4.0, 4.0, 4.0, 5.0];
+        let result = RecurringFeatureSet::new().apply(&series);
+        assert_eq!(result.len(), 5);
+        for feature in result {
+            let expected = match feature.name.as_str() {
+                "percentage_of_reoccurring_values_to_all_values" => 0.4,
+                "percentage_of_reoccurring_datapoints_to_all_datapoints" => 5.0 / 8.0,
+                "sum_of_reoccurring_values" => 5.0,
+                "sum_of_reoccurring_data_points" => 14.0,
+                "ratio_value_number_to_time_series_length" => 5.0 / 8.0,
+                name => panic!("unexpected feature {name}"),
+            };
+            assert_float_eq!(feature.value, expected);
+        }
+    }
+}
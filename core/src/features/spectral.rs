@@ -0,0 +1,15 @@
+//! Spectral feature extraction module.
+//!
+//! Provides frequency-domain features based on the discrete Fourier transform
+//! and Welch power spectral density estimates.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    FftAggType, FftAttribute, WelchConfig, WelchWindow, fft_aggregated, fft_coefficient, rfft,
+    spkt_welch_density, welch,
+};
+pub use oop::{FftAggregated, FftCoefficient, SpktWelchDensity};
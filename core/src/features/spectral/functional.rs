@@ -0,0 +1,244 @@
+//! Functional API for spectral feature extraction.
+
+use rustfft::{FftPlanner, num_complex::Complex};
+
+/// Part of a complex Fourier coefficient reported by [`fft_coefficient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftAttribute {
+    Real,
+    Imag,
+    Abs,
+    /// Angle in degrees.
+    Angle,
+}
+
+impl FftAttribute {
+    /// Returns the tsfresh name of the attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FftAttribute::Real => "real",
+            FftAttribute::Imag => "imag",
+            FftAttribute::Abs => "abs",
+            FftAttribute::Angle => "angle",
+        }
+    }
+
+    /// Extracts the attribute from a complex coefficient.
+    pub(crate) fn extract(&self, value: Complex<f64>) -> f64 {
+        match self {
+            FftAttribute::Real => value.re,
+            FftAttribute::Imag => value.im,
+            FftAttribute::Abs => value.norm(),
+            FftAttribute::Angle => value.arg().to_degrees(),
+        }
+    }
+}
+
+/// Statistic of the absolute spectrum reported by [`fft_aggregated`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftAggType {
+    Centroid,
+    Variance,
+    Skew,
+    Kurtosis,
+}
+
+impl FftAggType {
+    /// Returns the tsfresh name of the aggregation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FftAggType::Centroid => "centroid",
+            FftAggType::Variance => "variance",
+            FftAggType::Skew => "skew",
+            FftAggType::Kurtosis => "kurtosis",
+        }
+    }
+
+    /// Applies the aggregation to an absolute spectrum.
+    pub(crate) fn aggregate(&self, spectrum: &[f64]) -> f64 {
+        let centroid = spectral_moment(spectrum, 1);
+        let variance = spectral_moment(spectrum, 2) - centroid.powi(2);
+        match self {
+            FftAggType::Centroid => centroid,
+            FftAggType::Variance => variance,
+            // In the discrete limit the higher moments blow up as the variance
+            // vanishes, so tsfresh reports NaN below a resolution of 0.5.
+            FftAggType::Skew if variance < 0.5 => f64::NAN,
+            FftAggType::Skew => {
+                (spectral_moment(spectrum, 3) - 3.0 * centroid * variance - centroid.powi(3))
+                    / variance.powf(1.5)
+            }
+            FftAggType::Kurtosis if variance < 0.5 => f64::NAN,
+            // Mirrors tsfresh, including its `- 3 * centroid` last term.
+            FftAggType::Kurtosis => {
+                (spectral_moment(spectrum, 4) - 4.0 * centroid * spectral_moment(spectrum, 3)
+                    + 6.0 * spectral_moment(spectrum, 2) * centroid.powi(2)
+                    - 3.0 * centroid)
+                    / variance.powi(2)
+            }
+        }
+    }
+}
+
+/// Calculates the non-centered moment of a discrete distribution over bin indices.
+fn spectral_moment(spectrum: &[f64], moment: i32) -> f64 {
+    let weighted: f64 = spectrum
+        .iter()
+        .enumerate()
+        .map(|(i, &y)| y * (i as f64).powi(moment))
+        .sum();
+    weighted / spectrum.iter().sum::<f64>()
+}
+
+/// Computes the one-sided discrete Fourier transform of a real time series.
+///
+/// Returns the `n / 2 + 1` non-negative frequency terms, like `numpy.fft.rfft`.
+pub fn rfft(series: &[f64]) -> Vec<Complex<f64>> {
+    if series.is_empty() {
+        return Vec::new();
+    }
+    let mut buffer: Vec<Complex<f64>> = series.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    FftPlanner::new()
+        .plan_fft_forward(buffer.len())
+        .process(&mut buffer);
+    buffer.truncate(series.len() / 2 + 1);
+    buffer
+}
+
+/// Calculates an attribute of the `coeff`-th Fourier coefficient of a time series.
+///
+/// Returns NaN if the coefficient does not exist for the series length.
+pub fn fft_coefficient(series: &[f64], coeff: usize, attr: FftAttribute) -> f64 {
+    rfft(series)
+        .get(coeff)
+        .map_or(f64::NAN, |&value| attr.extract(value))
+}
+
+/// Calculates the centroid, variance, skew or kurtosis of the absolute Fourier spectrum.
+pub fn fft_aggregated(series: &[f64], aggtype: FftAggType) -> f64 {
+    let spectrum: Vec<f64> = rfft(series).iter().map(|c| c.norm()).collect();
+    aggtype.aggregate(&spectrum)
+}
+
+/// Window function applied to each segment in [`welch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WelchWindow {
+    #[default]
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl WelchWindow {
+    /// Returns the scipy name of the window.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WelchWindow::Hann => "hann",
+            WelchWindow::Hamming => "hamming",
+            WelchWindow::Blackman => "blackman",
+        }
+    }
+
+    /// Builds the periodic window of the given length, like `scipy.signal.get_window`.
+    fn coefficients(&self, length: usize) -> Vec<f64> {
+        if length <= 1 {
+            return vec![1.0; length];
+        }
+        let step = 2.0 * std::f64::consts::PI / length as f64;
+        (0..length)
+            .map(|k| {
+                let phase = step * k as f64;
+                match self {
+                    WelchWindow::Hann => 0.5 - 0.5 * phase.cos(),
+                    WelchWindow::Hamming => 0.54 - 0.46 * phase.cos(),
+                    WelchWindow::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Configuration of Welch's power spectral density estimate.
+///
+/// The default matches tsfresh: segments of at most 256 values, 50% overlap and
+/// a Hann window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WelchConfig {
+    /// Maximum number of values per segment. Shorter series use a single segment.
+    pub segment_length: usize,
+    /// Number of values shared by consecutive segments. `None` means half a segment.
+    pub overlap: Option<usize>,
+    pub window: WelchWindow,
+}
+
+impl WelchConfig {
+    pub const DEFAULT: Self = Self {
+        segment_length: 256,
+        overlap: None,
+        window: WelchWindow::Hann,
+    };
+}
+
+impl Default for WelchConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Estimates the power spectral density of a time series with Welch's method.
+///
+/// Mirrors `scipy.signal.welch` with unit sampling frequency, constant
+/// detrending and density scaling. Returns the `segment / 2 + 1` one-sided
+/// frequency bins, or an empty vector for an empty series.
+pub fn welch(series: &[f64], config: &WelchConfig) -> Vec<f64> {
+    let segment = config.segment_length.min(series.len());
+    if segment == 0 {
+        return Vec::new();
+    }
+    let overlap = config.overlap.unwrap_or(segment / 2).min(segment - 1);
+    let step = segment - overlap;
+    let window = config.window.coefficients(segment);
+    let scale = 1.0 / window.iter().map(|w| w * w).sum::<f64>();
+
+    let fft = FftPlanner::new().plan_fft_forward(segment);
+    let bins = segment / 2 + 1;
+    let mut density = vec![0.0; bins];
+    let mut buffer = vec![Complex::new(0.0, 0.0); segment];
+    let num_segments = (series.len() - segment) / step + 1;
+    for start in (0..num_segments).map(|i| i * step) {
+        let values = &series[start..start + segment];
+        let offset = values.iter().sum::<f64>() / segment as f64;
+        for ((slot, &x), &w) in buffer.iter_mut().zip(values).zip(&window) {
+            *slot = Complex::new((x - offset) * w, 0.0);
+        }
+        fft.process(&mut buffer);
+        for (d, c) in density.iter_mut().zip(&buffer) {
+            *d += c.norm_sqr();
+        }
+    }
+
+    // Fold the negative frequencies onto the positive ones, except for DC and,
+    // for even segment lengths, the Nyquist bin.
+    let last_doubled = if segment.is_multiple_of(2) {
+        bins - 1
+    } else {
+        bins
+    };
+    for (i, d) in density.iter_mut().enumerate() {
+        *d *= scale / num_segments as f64;
+        if i > 0 && i < last_doubled {
+            *d *= 2.0;
+        }
+    }
+    density
+}
+
+/// Calculates the Welch power spectral density of a time series at bin `coeff`.
+///
+/// Returns NaN if the bin does not exist for the series length.
+pub fn spkt_welch_density(series: &[f64], coeff: usize, config: &WelchConfig) -> f64 {
+    welch(series, config)
+        .get(coeff)
+        .copied()
+        .unwrap_or(f64::NAN)
+}
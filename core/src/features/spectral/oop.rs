@@ -0,0 +1,239 @@
+//! OOP API for spectral feature extraction.
+
+use crate::features::common::{
+    FeatureFunction, FeatureFunctionReturn, FormatParam, format_feature_name,
+};
+
+use super::functional::{FftAggType, FftAttribute, WelchConfig, rfft, welch};
+
+/// Feature function that reports attributes of selected Fourier coefficients.
+///
+/// The FFT is computed once per series and shared by all `(coeff, attr)` pairs.
+pub struct FftCoefficient {
+    pub params: Vec<(usize, FftAttribute)>,
+}
+
+impl FftCoefficient {
+    pub fn new(params: Vec<(usize, FftAttribute)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for FftCoefficient {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let fft = rfft(series);
+        self.params
+            .iter()
+            .map(|&(coeff, attr)| FeatureFunctionReturn {
+                name: format_feature_name(
+                    "fft_coefficient",
+                    &[("attr", &attr.as_str()), ("coeff", &coeff)],
+                ),
+                value: fft
+                    .get(coeff)
+                    .map_or(f64::NAN, |&value| attr.extract(value)),
+            })
+            .collect()
+    }
+}
+
+/// Feature function that reports statistics of the absolute Fourier spectrum.
+///
+/// The spectrum is computed once per series and shared by all aggregations.
+pub struct FftAggregated {
+    pub aggtypes: Vec<FftAggType>,
+}
+
+impl FftAggregated {
+    pub fn new(aggtypes: Vec<FftAggType>) -> Self {
+        Self { aggtypes }
+    }
+}
+
+impl FeatureFunction for FftAggregated {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let spectrum: Vec<f64> = rfft(series).iter().map(|c| c.norm()).collect();
+        self.aggtypes
+            .iter()
+            .map(|aggtype| FeatureFunctionReturn {
+                name: format_feature_name("fft_aggregated", &[("aggtype", &aggtype.as_str())]),
+                value: aggtype.aggregate(&spectrum),
+            })
+            .collect()
+    }
+}
+
+/// Feature function that reports Welch power spectral density bins.
+///
+/// The density is estimated once per series and shared by all coefficients.
+/// Settings that differ from [`WelchConfig::DEFAULT`] are included in the
+/// feature names.
+pub struct SpktWelchDensity {
+    pub coeffs: Vec<usize>,
+    pub config: WelchConfig,
+}
+
+impl SpktWelchDensity {
+    pub fn new(coeffs: Vec<usize>, config: WelchConfig) -> Self {
+        Self { coeffs, config }
+    }
+
+    fn feature_name(&self, coeff: usize) -> String {
+        let default = WelchConfig::DEFAULT;
+        let window = self.config.window.as_str();
+        let mut params: Vec<(&str, &dyn FormatParam)> = vec![("coeff", &coeff)];
+        if self.config.segment_length != default.segment_length {
+            params.push(("segment_length", &self.config.segment_length));
+        }
+        if let Some(overlap) = &self.config.overlap {
+            params.push(("overlap", overlap));
+        }
+        if self.config.window != default.window {
+            params.push(("window", &window));
+        }
+        format_feature_name("spkt_welch_density", &params)
+    }
+}
+
+impl FeatureFunction for SpktWelchDensity {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let density = welch(series, &self.config);
+        self.coeffs
+            .iter()
+            .map(|&coeff| FeatureFunctionReturn {
+                name: self.feature_name(coeff),
+                value: density.get(coeff).copied().unwrap_or(f64::NAN),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_float_eq, features::spectral::WelchWindow};
+
+    #[test]
+    fn test_fft_coefficient() {
+        let series = [1.0, 2.0, 3.0, 4.0];
+        let result = FftCoefficient::new(vec![
+            (0, FftAttribute::Real),
+            (1, FftAttribute::Real),
+            (1, FftAttribute::Imag),
+            (1, FftAttribute::Abs),
+            (1, FftAttribute::Angle),
+            (2, FftAttribute::Imag),
+            (3, FftAttribute::Real),
+        ])
+        .apply(&series);
+        assert_eq!(result[0].name, "fft_coefficient__attr_\"real\"__coeff_0");
+        assert_float_eq!(result[0].value, 10.0);
+        assert_float_eq!(result[1].value, -2.0);
+        assert_eq!(result[2].name, "fft_coefficient__attr_\"imag\"__coeff_1");
+        assert_float_eq!(result[2].value, 2.0);
+        assert_float_eq!(result[3].value, 8.0f64.sqrt());
+        assert_eq!(result[4].name, "fft_coefficient__attr_\"angle\"__coeff_1");
+        assert_float_eq!(result[4].value, 135.0);
+        assert_float_eq!(result[5].value, 0.0);
+        assert!(result[6].value.is_nan());
+    }
+
+    #[test]
+    fn test_fft_coefficient_empty() {
+        let empty: [f64; 0] = [];
+        let result = FftCoefficient::new(vec![(0, FftAttribute::Abs)]).apply(&empty);
+        assert!(result[0].value.is_nan());
+    }
+
+    #[test]
+    fn test_fft_aggregated() {
+        let aggtypes = vec![
+            FftAggType::Centroid,
+            FftAggType::Variance,
+            FftAggType::Skew,
+            FftAggType::Kurtosis,
+        ];
+        let series = [1.0, 2.0, 3.0, 4.0];
+        let result = FftAggregated::new(aggtypes.clone()).apply(&series);
+        assert_eq!(result[0].name, "fft_aggregated__aggtype_\"centroid\"");
+        assert_float_eq!(result[0].value, 0.4604957132203641);
+        assert_float_eq!(result[1].value, 0.5181915547158503);
+        assert_float_eq!(result[2].value, 1.223051331636656);
+        assert_float_eq!(result[3].value, -1.6477805748432157);
+
+        let series = [0.0, 1.0, 0.0, -1.0, 0.0, 1.0, 0.0, -1.0, 2.0, 5.0, 1.0, 0.0];
+        let result = FftAggregated::new(aggtypes).apply(&series);
+        assert_float_eq!(result[0].value, 2.173285987230637);
+        assert_float_eq!(result[1].value, 3.063190711342701);
+        assert_float_eq!(result[2].value, 0.4777075946220535);
+        assert_float_eq!(result[3].value, 8.807927961556862);
+    }
+
+    #[test]
+    fn test_fft_aggregated_low_variance() {
+        let series = [1.0, 1.0, 1.0, 1.0];
+        let result =
+            FftAggregated::new(vec![FftAggType::Skew, FftAggType::Kurtosis]).apply(&series);
+        assert!(result[0].value.is_nan());
+        assert!(result[1].value.is_nan());
+    }
+
+    fn welch_test_series() -> Vec<f64> {
+        (0..20)
+            .map(|i| (0.7 * i as f64).sin() + 0.1 * i as f64)
+            .collect()
+    }
+
+    #[test]
+    fn test_spkt_welch_density() {
+        let series = welch_test_series();
+        let result =
+            SpktWelchDensity::new(vec![1, 2, 3, 11], WelchConfig::default()).apply(&series);
+        assert_eq!(result[0].name, "spkt_welch_density__coeff_1");
+        assert_float_eq!(result[0].value, 3.886975618512385, 1e-9);
+        assert_float_eq!(result[1].value, 7.346218695803351, 1e-9);
+        assert_float_eq!(result[2].value, 2.8196608063997672, 1e-9);
+        assert!(result[3].value.is_nan());
+    }
+
+    #[test]
+    fn test_spkt_welch_density_config() {
+        let series = welch_test_series();
+        let config = WelchConfig {
+            segment_length: 8,
+            ..WelchConfig::default()
+        };
+        let result = SpktWelchDensity::new(vec![0, 1, 2], config).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "spkt_welch_density__coeff_0__segment_length_8"
+        );
+        assert_float_eq!(result[0].value, 0.5534032454297878, 1e-9);
+        assert_float_eq!(result[1].value, 2.2046719475017267, 1e-9);
+        assert_float_eq!(result[2].value, 0.46108028727258826, 1e-9);
+
+        let config = WelchConfig {
+            segment_length: 7,
+            overlap: Some(2),
+            window: WelchWindow::Hamming,
+        };
+        let result = SpktWelchDensity::new(vec![0, 1, 2], config).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "spkt_welch_density__coeff_0__overlap_2__segment_length_7__window_\"hamming\""
+        );
+        assert_float_eq!(result[0].value, 0.07766210134458093, 1e-9);
+        assert_float_eq!(result[1].value, 1.898981344126683, 1e-9);
+        assert_float_eq!(result[2].value, 0.18848783656451074, 1e-9);
+
+        let config = WelchConfig {
+            segment_length: 8,
+            overlap: None,
+            window: WelchWindow::Blackman,
+        };
+        let result = SpktWelchDensity::new(vec![0, 1, 2], config).apply(&series);
+        assert_float_eq!(result[0].value, 0.6783137200671467, 1e-9);
+        assert_float_eq!(result[1].value, 1.8996606188403513, 1e-9);
+        assert_float_eq!(result[2].value, 0.5935780746348747, 1e-9);
+    }
+}
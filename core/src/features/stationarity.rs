@@ -0,0 +1,11 @@
+//! Stationarity feature extraction module.
+//!
+//! Provides unit root tests such as the augmented Dickey-Fuller test.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{AdfAttribute, AdfResult, Autolag, augmented_dickey_fuller};
+pub use oop::AugmentedDickeyFuller;
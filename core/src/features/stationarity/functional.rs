@@ -0,0 +1,204 @@
+//! Functional API for stationarity feature extraction.
+
+use crate::features::common::FormatParam;
+use crate::math::{LeastSquares, least_squares, normal_cdf};
+
+/// Information criterion used to choose the number of lagged differences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Autolag {
+    /// Akaike information criterion.
+    Aic,
+    /// Bayesian information criterion.
+    Bic,
+    /// Drop lags from the largest down until the last one is significant at 5%.
+    TStat,
+}
+
+impl Autolag {
+    /// Returns the statsmodels name of the method.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Autolag::Aic => "AIC",
+            Autolag::Bic => "BIC",
+            Autolag::TStat => "t-stat",
+        }
+    }
+}
+
+impl FormatParam for Option<Autolag> {
+    /// Renders `None` the way tsfresh does, as `"None"`.
+    fn format_param(&self) -> String {
+        self.map_or("None", |autolag| autolag.as_str())
+            .format_param()
+    }
+}
+
+/// Outcome of the augmented Dickey-Fuller test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdfResult {
+    /// t statistic of the lagged level coefficient.
+    pub teststat: f64,
+    /// MacKinnon approximate p-value of the test statistic.
+    pub pvalue: f64,
+    /// Number of lagged differences in the test regression.
+    pub usedlag: usize,
+}
+
+/// Value of an [`AdfResult`] reported as a feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdfAttribute {
+    TestStat,
+    PValue,
+    UsedLag,
+}
+
+impl AdfAttribute {
+    /// Returns the tsfresh name of the attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdfAttribute::TestStat => "teststat",
+            AdfAttribute::PValue => "pvalue",
+            AdfAttribute::UsedLag => "usedlag",
+        }
+    }
+
+    /// Extracts the attribute from a test result, or NaN if the test failed.
+    pub(crate) fn extract(&self, result: Option<&AdfResult>) -> f64 {
+        match (self, result) {
+            (_, None) => f64::NAN,
+            (AdfAttribute::TestStat, Some(result)) => result.teststat,
+            (AdfAttribute::PValue, Some(result)) => result.pvalue,
+            (AdfAttribute::UsedLag, Some(result)) => result.usedlag as f64,
+        }
+    }
+}
+
+impl FormatParam for AdfAttribute {
+    fn format_param(&self) -> String {
+        self.as_str().format_param()
+    }
+}
+
+/// One-sided 5% critical value of the standard normal distribution
+/// (`norm.ppf(0.95)`), which statsmodels compares the absolute t-value of the
+/// last lag against in the `t-stat` lag selection.
+const T_STAT_CRITICAL_VALUE: f64 = 1.6448536269514722;
+
+/// Performs the augmented Dickey-Fuller unit root test with a constant term.
+///
+/// Mirrors statsmodels' `adfuller(x, regression="c", autolag=...)`: the maximum
+/// lag is `12 * (n / 100)^(1/4)` capped by the sample size, and with an
+/// `autolag` method all candidate lags are fitted on the same sample before the
+/// chosen regression is rerun on the full sample. Without `autolag` the maximum
+/// lag is used.
+///
+/// Returns `None` if the series is constant, contains non-finite values, is too
+/// short for the test regression, or the regression is singular.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::stationarity::{Autolag, augmented_dickey_fuller};
+///
+/// let series: Vec<f64> = (0..50).map(|i| (0.7 * (i * i) as f64).sin()).collect();
+/// let result = augmented_dickey_fuller(&series, Some(Autolag::Aic)).unwrap();
+/// assert!(result.pvalue < 0.05);
+/// ```
+pub fn augmented_dickey_fuller(series: &[f64], autolag: Option<Autolag>) -> Option<AdfResult> {
+    if series.iter().any(|x| !x.is_finite()) {
+        return None;
+    }
+    let nobs = series.len();
+    let max_lag = (12.0 * (nobs as f64 / 100.0).powf(0.25)).ceil() as usize;
+    let max_lag = max_lag.min((nobs / 2).checked_sub(2)?);
+    let first = *series.first()?;
+    if series.iter().all(|&x| x == first) {
+        return None;
+    }
+    let diff: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let usedlag = match autolag {
+        Some(method) => select_lag(series, &diff, max_lag, method)?,
+        None => max_lag,
+    };
+    let fit = adf_regression(series, &diff, usedlag, usedlag)?;
+    let teststat = fit.t_values()[0];
+    Some(AdfResult {
+        teststat,
+        pvalue: mackinnon_p_value(teststat),
+        usedlag,
+    })
+}
+
+/// Regresses `diff[t]` on `series[t]`, `diff[t - 1..=t - lags]` and a constant,
+/// for `t` starting at `start`.
+fn adf_regression(series: &[f64], diff: &[f64], lags: usize, start: usize) -> Option<LeastSquares> {
+    let rows = start..diff.len();
+    let mut columns = Vec::with_capacity(lags + 2);
+    columns.push(series[rows.clone()].to_vec());
+    for lag in 1..=lags {
+        columns.push(diff[start - lag..diff.len() - lag].to_vec());
+    }
+    columns.push(vec![1.0; rows.len()]);
+    least_squares(&columns, &diff[rows])
+}
+
+/// Chooses the number of lagged differences, fitting every candidate on the
+/// sample left after dropping `max_lag` observations.
+fn select_lag(series: &[f64], diff: &[f64], max_lag: usize, method: Autolag) -> Option<usize> {
+    let fits = (0..=max_lag)
+        .map(|lags| adf_regression(series, diff, lags, max_lag))
+        .collect::<Option<Vec<_>>>()?;
+    let criterion = |fit: &LeastSquares, penalty: f64| {
+        -2.0 * fit.log_likelihood() + penalty * fit.coefficients.len() as f64
+    };
+    let minimize = |penalty: f64| {
+        // Ties go to the smaller lag, like statsmodels.
+        (0..fits.len()).reduce(|best, lags| {
+            if criterion(&fits[lags], penalty) < criterion(&fits[best], penalty) {
+                lags
+            } else {
+                best
+            }
+        })
+    };
+    match method {
+        Autolag::Aic => minimize(2.0),
+        Autolag::Bic => minimize(((diff.len() - max_lag) as f64).ln()),
+        Autolag::TStat => Some(
+            (1..=max_lag)
+                .rev()
+                .find(|&lags| {
+                    // The last lagged difference sits just before the constant.
+                    fits[lags].t_values()[lags].abs() >= T_STAT_CRITICAL_VALUE
+                })
+                .unwrap_or(0),
+        ),
+    }
+}
+
+/// MacKinnon (1994) approximate p-value for a test with a constant and one
+/// variable.
+fn mackinnon_p_value(teststat: f64) -> f64 {
+    const MAX_STAT: f64 = 2.74;
+    const MIN_STAT: f64 = -18.83;
+    const STAR_STAT: f64 = -1.61;
+    const SMALL_P: [f64; 3] = [2.1659, 1.4412, 0.038269];
+    const LARGE_P: [f64; 4] = [1.7339, 0.93202, -0.12745, -0.010368];
+    if teststat > MAX_STAT {
+        return 1.0;
+    }
+    if teststat < MIN_STAT {
+        return 0.0;
+    }
+    let coefficients: &[f64] = if teststat <= STAR_STAT {
+        &SMALL_P
+    } else {
+        &LARGE_P
+    };
+    let polynomial = coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |acc, c| acc * teststat + c);
+    normal_cdf(polynomial)
+}
@@ -0,0 +1,151 @@
+//! OOP API for stationarity feature extraction.
+
+use std::collections::HashMap;
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{AdfAttribute, Autolag, augmented_dickey_fuller};
+
+/// Feature function that reports attributes of the augmented Dickey-Fuller test.
+///
+/// Each parameter is an `(attr, autolag)` pair. The test runs once per series
+/// for every distinct `autolag` method.
+pub struct AugmentedDickeyFuller {
+    pub params: Vec<(AdfAttribute, Option<Autolag>)>,
+}
+
+impl AugmentedDickeyFuller {
+    pub fn new(params: Vec<(AdfAttribute, Option<Autolag>)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for AugmentedDickeyFuller {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut results = HashMap::new();
+        self.params
+            .iter()
+            .map(|(attr, autolag)| {
+                let result = results
+                    .entry(*autolag)
+                    .or_insert_with(|| augmented_dickey_fuller(series, *autolag));
+                FeatureFunctionReturn {
+                    name: format_feature_name(
+                        "augmented_dickey_fuller",
+                        &[("attr", attr), ("autolag", autolag)],
+                    ),
+                    value: attr.extract(result.as_ref()),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    fn stationary_series() -> Vec<f64> {
+        let mut state = 0.0;
+        (0..100)
+            .map(|i| {
+                let t = i as f64;
+                state = 0.6 * state + (1.3 * t).sin() + 0.5 * (0.37 * t * t).cos();
+                state
+            })
+            .collect()
+    }
+
+    fn drifting_series() -> Vec<f64> {
+        let mut level = 0.0;
+        (0..80)
+            .map(|i| {
+                let t = i as f64;
+                level += (2.3 * t * t).sin() + 0.05;
+                level
+            })
+            .collect()
+    }
+
+    fn all_attributes(autolag: Option<Autolag>) -> Vec<(AdfAttribute, Option<Autolag>)> {
+        vec![
+            (AdfAttribute::TestStat, autolag),
+            (AdfAttribute::PValue, autolag),
+            (AdfAttribute::UsedLag, autolag),
+        ]
+    }
+
+    #[test]
+    fn test_augmented_dickey_fuller_aic() {
+        let series = stationary_series();
+        let result = AugmentedDickeyFuller::new(all_attributes(Some(Autolag::Aic))).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "augmented_dickey_fuller__attr_\"teststat\"__autolag_\"AIC\""
+        );
+        assert_float_eq!(result[0].value, -1.6056053234391165, 1e-9);
+        assert_float_eq!(result[1].value, 0.480777178333481, 1e-9);
+        assert_float_eq!(result[2].value, 8.0);
+
+        let series = drifting_series();
+        let result = AugmentedDickeyFuller::new(all_attributes(Some(Autolag::Aic))).apply(&series);
+        assert_float_eq!(result[0].value, -1.4769925859016935, 1e-9);
+        assert_float_eq!(result[1].value, 0.5448605310496459, 1e-9);
+        assert_float_eq!(result[2].value, 0.0);
+    }
+
+    #[test]
+    fn test_augmented_dickey_fuller_bic_and_t_stat() {
+        let series = stationary_series();
+        let result = AugmentedDickeyFuller::new(all_attributes(Some(Autolag::Bic))).apply(&series);
+        assert_eq!(
+            result[1].name,
+            "augmented_dickey_fuller__attr_\"pvalue\"__autolag_\"BIC\""
+        );
+        assert_float_eq!(result[0].value, -1.6056053234391165, 1e-9);
+        assert_float_eq!(result[2].value, 8.0);
+
+        let series = drifting_series();
+        let result =
+            AugmentedDickeyFuller::new(all_attributes(Some(Autolag::TStat))).apply(&series);
+        assert_eq!(
+            result[2].name,
+            "augmented_dickey_fuller__attr_\"usedlag\"__autolag_\"t-stat\""
+        );
+        assert_float_eq!(result[0].value, -1.883401310229167, 1e-9);
+        assert_float_eq!(result[1].value, 0.339909492794621, 1e-9);
+        assert_float_eq!(result[2].value, 4.0);
+    }
+
+    #[test]
+    fn test_augmented_dickey_fuller_fixed_lag() {
+        let series = stationary_series();
+        let result = AugmentedDickeyFuller::new(all_attributes(None)).apply(&series);
+        assert_eq!(
+            result[0].name,
+            "augmented_dickey_fuller__attr_\"teststat\"__autolag_\"None\""
+        );
+        assert_float_eq!(result[0].value, -1.4895247938900114, 1e-9);
+        assert_float_eq!(result[1].value, 0.5386867627498549, 1e-9);
+        assert_float_eq!(result[2].value, 12.0);
+
+        let series = drifting_series();
+        let result = AugmentedDickeyFuller::new(all_attributes(None)).apply(&series);
+        assert_float_eq!(result[0].value, -1.5065244657409125, 1e-9);
+        assert_float_eq!(result[1].value, 0.5302813453435629, 1e-9);
+    }
+
+    #[test]
+    fn test_augmented_dickey_fuller_invalid_input() {
+        let params = all_attributes(Some(Autolag::Aic));
+        for series in [vec![2.0; 20], vec![1.0, 2.0, 3.0], vec![], {
+            let mut series = stationary_series();
+            series[10] = f64::NAN;
+            series
+        }] {
+            let result = AugmentedDickeyFuller::new(params.clone()).apply(&series);
+            assert!(result.iter().all(|r| r.value.is_nan()));
+        }
+    }
+}
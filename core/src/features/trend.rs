@@ -0,0 +1,16 @@
+//! Trend feature extraction module.
+//!
+//! Provides features based on linear least-squares regressions of the series
+//! (or of chunk aggregates of it) against time, measured either by position or
+//! by real timestamps.
+//! Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{
+    LinearRegression, TrendAttribute, agg_linear_trend, linear_trend, linear_trend_timewise,
+    linregress,
+};
+pub use oop::{AggLinearTrend, LinearTrend, LinearTrendTimewise};
@@ -0,0 +1,212 @@
+//! Functional API for trend feature extraction.
+
+use crate::features::common::{Aggregation, FormatParam, Timestamps};
+use crate::math::student_t_sf;
+
+/// Result of a simple linear regression, mirroring `scipy.stats.linregress`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRegression {
+    pub slope: f64,
+    pub intercept: f64,
+    /// Pearson correlation coefficient.
+    pub rvalue: f64,
+    /// Two-sided p-value of the null hypothesis that the slope is zero.
+    pub pvalue: f64,
+    /// Standard error of the slope.
+    pub stderr: f64,
+}
+
+/// Attribute of a [`LinearRegression`] reported as a feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendAttribute {
+    PValue,
+    RValue,
+    Intercept,
+    Slope,
+    StdErr,
+}
+
+impl TrendAttribute {
+    /// Returns the scipy name of the attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendAttribute::PValue => "pvalue",
+            TrendAttribute::RValue => "rvalue",
+            TrendAttribute::Intercept => "intercept",
+            TrendAttribute::Slope => "slope",
+            TrendAttribute::StdErr => "stderr",
+        }
+    }
+
+    /// Extracts the attribute from a regression, or NaN if there is none.
+    pub(crate) fn extract(&self, regression: Option<&LinearRegression>) -> f64 {
+        match (self, regression) {
+            (_, None) => f64::NAN,
+            (TrendAttribute::PValue, Some(regression)) => regression.pvalue,
+            (TrendAttribute::RValue, Some(regression)) => regression.rvalue,
+            (TrendAttribute::Intercept, Some(regression)) => regression.intercept,
+            (TrendAttribute::Slope, Some(regression)) => regression.slope,
+            (TrendAttribute::StdErr, Some(regression)) => regression.stderr,
+        }
+    }
+}
+
+impl FormatParam for TrendAttribute {
+    fn format_param(&self) -> String {
+        self.as_str().format_param()
+    }
+}
+
+/// Fits `y = intercept + slope * x` by least squares, like `scipy.stats.linregress`.
+///
+/// Returns `None` if `x` and `y` differ in length or all `x` values are identical
+/// (which includes inputs with fewer than two points).
+pub fn linregress(x: &[f64], y: &[f64]) -> Option<LinearRegression> {
+    let n = x.len();
+    if n != y.len() || n == 0 {
+        return None;
+    }
+    let x_mean = x.iter().sum::<f64>() / n as f64;
+    let y_mean = y.iter().sum::<f64>() / n as f64;
+    let (mut ssxm, mut ssym, mut ssxym) = (0.0, 0.0, 0.0);
+    for (&xi, &yi) in x.iter().zip(y) {
+        ssxm += (xi - x_mean).powi(2);
+        ssym += (yi - y_mean).powi(2);
+        ssxym += (xi - x_mean) * (yi - y_mean);
+    }
+    if ssxm == 0.0 {
+        return None;
+    }
+    let (ssxm, ssym, ssxym) = (ssxm / n as f64, ssym / n as f64, ssxym / n as f64);
+
+    let r_den = (ssxm * ssym).sqrt();
+    let rvalue = if r_den == 0.0 {
+        0.0
+    } else {
+        (ssxym / r_den).clamp(-1.0, 1.0)
+    };
+    let slope = ssxym / ssxm;
+    let intercept = y_mean - slope * x_mean;
+    let (pvalue, stderr) = if n == 2 {
+        // A line through two points is exact; scipy reports a perfect fit.
+        (if y[0] == y[1] { 1.0 } else { 0.0 }, 0.0)
+    } else {
+        let df = (n - 2) as f64;
+        // scipy's guard against division by zero for |r| = 1.
+        let tiny = 1e-20;
+        let t = rvalue * (df / ((1.0 - rvalue + tiny) * (1.0 + rvalue + tiny))).sqrt();
+        let pvalue = 2.0 * student_t_sf(t.abs(), df);
+        let stderr = ((1.0 - rvalue * rvalue) * ssym / ssxm / df).sqrt();
+        (pvalue, stderr)
+    };
+    Some(LinearRegression {
+        slope,
+        intercept,
+        rvalue,
+        pvalue,
+        stderr,
+    })
+}
+
+/// Regresses the series on the sequence `0, 1, …, n - 1`.
+pub(crate) fn trend_regression(series: &[f64]) -> Option<LinearRegression> {
+    let time: Vec<f64> = (0..series.len()).map(|t| t as f64).collect();
+    linregress(&time, series)
+}
+
+/// Calculates an attribute of the linear least-squares regression of the series
+/// against the sequence `0, 1, …, n - 1`.
+///
+/// Returns NaN for series with fewer than two values.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::trend::{TrendAttribute, linear_trend};
+///
+/// let series = [1.0, 3.0, 5.0, 7.0];
+/// assert_eq!(linear_trend(&series, TrendAttribute::Slope), 2.0);
+/// assert_eq!(linear_trend(&series, TrendAttribute::Intercept), 1.0);
+/// ```
+pub fn linear_trend(series: &[f64], attr: TrendAttribute) -> f64 {
+    attr.extract(trend_regression(series).as_ref())
+}
+
+/// Regresses the series on the hours elapsed since its first timestamp.
+pub(crate) fn timewise_regression(
+    series: &[f64],
+    timestamps: &Timestamps<'_>,
+) -> Option<LinearRegression> {
+    linregress(&timestamps.hours_since_start(), series)
+}
+
+/// Calculates an attribute of the linear least-squares regression of the series
+/// against its timestamps, measured in hours since the first one.
+///
+/// The slope is therefore expressed per hour, as in tsfresh. Returns NaN if the
+/// number of timestamps differs from the series length or all timestamps are
+/// identical.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::common::Timestamps;
+/// use kalax::features::trend::{TrendAttribute, linear_trend_timewise};
+///
+/// let series = [1.0, 3.0, 5.0];
+/// let seconds = [0.0, 1800.0, 3600.0];
+/// let slope = linear_trend_timewise(&series, Timestamps::Seconds(&seconds), TrendAttribute::Slope);
+/// assert_eq!(slope, 4.0);
+/// ```
+pub fn linear_trend_timewise(
+    series: &[f64],
+    timestamps: Timestamps<'_>,
+    attr: TrendAttribute,
+) -> f64 {
+    attr.extract(timewise_regression(series, &timestamps).as_ref())
+}
+
+/// Aggregates consecutive chunks of `chunk_len` values with `f_agg`.
+///
+/// The last chunk holds the remaining values and may be shorter, like tsfresh's
+/// `_aggregate_on_chunks`. `chunk_len` must be positive.
+pub(crate) fn aggregate_on_chunks(
+    series: &[f64],
+    f_agg: Aggregation,
+    chunk_len: usize,
+) -> Vec<f64> {
+    series
+        .chunks(chunk_len)
+        .map(|chunk| f_agg.apply(chunk))
+        .collect()
+}
+
+/// Regresses the chunk aggregates of the series on their chunk index.
+///
+/// Returns `None` if `chunk_len` is zero or not smaller than the series length.
+pub(crate) fn chunk_trend_regression(
+    series: &[f64],
+    chunk_len: usize,
+    f_agg: Aggregation,
+) -> Option<LinearRegression> {
+    if chunk_len == 0 || chunk_len >= series.len() {
+        return None;
+    }
+    trend_regression(&aggregate_on_chunks(series, f_agg, chunk_len))
+}
+
+/// Calculates an attribute of the linear least-squares regression of chunk
+/// aggregates against the chunk index.
+///
+/// The series is split into chunks of `chunk_len` values (the last one may be
+/// shorter), each chunk is aggregated with `f_agg` and the aggregates are
+/// regressed on `0, 1, …`. Returns NaN if `chunk_len` is zero or not smaller
+/// than the series length.
+pub fn agg_linear_trend(
+    series: &[f64],
+    attr: TrendAttribute,
+    chunk_len: usize,
+    f_agg: Aggregation,
+) -> f64 {
+    attr.extract(chunk_trend_regression(series, chunk_len, f_agg).as_ref())
+}
@@ -0,0 +1,296 @@
+//! OOP API for trend feature extraction.
+
+use std::collections::HashMap;
+
+use crate::features::common::{
+    Aggregation, FeatureFunction, FeatureFunctionReturn, TimeSeries, format_feature_name,
+};
+
+use super::functional::{
+    LinearRegression, TrendAttribute, chunk_trend_regression, timewise_regression, trend_regression,
+};
+
+/// Feature function that reports attributes of the linear trend of the series.
+///
+/// The regression runs once per series for all requested attributes.
+pub struct LinearTrend {
+    pub attrs: Vec<TrendAttribute>,
+}
+
+impl LinearTrend {
+    pub fn new(attrs: Vec<TrendAttribute>) -> Self {
+        Self { attrs }
+    }
+}
+
+impl FeatureFunction for LinearTrend {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let regression = trend_regression(series);
+        self.attrs
+            .iter()
+            .map(|attr| FeatureFunctionReturn {
+                name: format_feature_name("linear_trend", &[("attr", attr)]),
+                value: attr.extract(regression.as_ref()),
+            })
+            .collect()
+    }
+}
+
+/// Feature function that reports attributes of the linear trend of the series
+/// against its timestamps, with the slope expressed per hour.
+///
+/// Only [`apply_timed`](FeatureFunction::apply_timed) with timestamps yields
+/// values; without timestamps every attribute is NaN.
+pub struct LinearTrendTimewise {
+    pub attrs: Vec<TrendAttribute>,
+}
+
+impl LinearTrendTimewise {
+    pub fn new(attrs: Vec<TrendAttribute>) -> Self {
+        Self { attrs }
+    }
+
+    fn report(&self, regression: Option<&LinearRegression>) -> Vec<FeatureFunctionReturn> {
+        self.attrs
+            .iter()
+            .map(|attr| FeatureFunctionReturn {
+                name: format_feature_name("linear_trend_timewise", &[("attr", attr)]),
+                value: attr.extract(regression),
+            })
+            .collect()
+    }
+}
+
+impl FeatureFunction for LinearTrendTimewise {
+    fn apply(&self, _series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        self.report(None)
+    }
+
+    fn apply_timed(&self, series: &TimeSeries<'_>) -> Vec<FeatureFunctionReturn> {
+        let regression = series
+            .timestamps
+            .and_then(|timestamps| timewise_regression(series.values, &timestamps));
+        self.report(regression.as_ref())
+    }
+}
+
+/// Feature function that reports attributes of the linear trend of chunk aggregates.
+///
+/// Each parameter is an `(attr, chunk_len, f_agg)` triple. The regression runs
+/// once per series for every distinct `(chunk_len, f_agg)` pair.
+pub struct AggLinearTrend {
+    pub params: Vec<(TrendAttribute, usize, Aggregation)>,
+}
+
+impl AggLinearTrend {
+    pub fn new(params: Vec<(TrendAttribute, usize, Aggregation)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for AggLinearTrend {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut regressions = HashMap::new();
+        self.params
+            .iter()
+            .map(|(attr, chunk_len, f_agg)| {
+                let regression = regressions
+                    .entry((*chunk_len, *f_agg))
+                    .or_insert_with(|| chunk_trend_regression(series, *chunk_len, *f_agg));
+                FeatureFunctionReturn {
+                    name: format_feature_name(
+                        "agg_linear_trend",
+                        &[("attr", attr), ("chunk_len", chunk_len), ("f_agg", f_agg)],
+                    ),
+                    value: attr.extract(regression.as_ref()),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_float_eq,
+        features::{common::Timestamps, trend::linregress},
+        test_utils::synthetic_series,
+    };
+
+    const ALL_ATTRIBUTES: [TrendAttribute; 5] = [
+        TrendAttribute::PValue,
+        TrendAttribute::RValue,
+        TrendAttribute::Intercept,
+        TrendAttribute::Slope,
+        TrendAttribute::StdErr,
+    ];
+
+    fn assert_regression(result: &[FeatureFunctionReturn], expected: [f64; 5]) {
+        for (feature, expected) in result.iter().zip(expected) {
+            assert_float_eq!(feature.value, expected, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_linear_trend() {
+        let result = LinearTrend::new(ALL_ATTRIBUTES.to_vec()).apply(&synthetic_series(23));
+        assert_eq!(result[0].name, "linear_trend__attr_\"pvalue\"");
+        assert_eq!(result[4].name, "linear_trend__attr_\"stderr\"");
+        assert_regression(
+            &result,
+            [
+                0.013429423358183555,
+                -0.5075383431387424,
+                0.6607947689962907,
+                -0.053006003483502394,
+                0.019636629690525893,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_linear_trend_degenerate() {
+        let result = LinearTrend::new(ALL_ATTRIBUTES.to_vec()).apply(&[3.0, 5.0]);
+        assert_regression(&result, [0.0, 1.0, 3.0, 2.0, 0.0]);
+
+        let result = LinearTrend::new(ALL_ATTRIBUTES.to_vec()).apply(&[2.0, 2.0, 2.0]);
+        assert_regression(&result, [1.0, 0.0, 2.0, 0.0, 0.0]);
+
+        let result = LinearTrend::new(ALL_ATTRIBUTES.to_vec()).apply(&[1.0]);
+        assert!(result.iter().all(|r| r.value.is_nan()));
+    }
+
+    #[test]
+    fn test_linear_trend_timewise() {
+        let series = synthetic_series(23);
+        let feature = LinearTrendTimewise::new(ALL_ATTRIBUTES.to_vec());
+
+        // Hourly timestamps reproduce the positional trend.
+        let start = 1_700_000_000_000_000_000i64;
+        let hourly: Vec<i64> = (0..23).map(|i| start + i * 3_600_000_000_000).collect();
+        let timed = TimeSeries::new(&series).with_timestamps(Timestamps::Nanoseconds(&hourly));
+        let result = feature.apply_timed(&timed);
+        assert_eq!(result[0].name, "linear_trend_timewise__attr_\"pvalue\"");
+        assert_regression(
+            &result,
+            [
+                0.013429423358183555,
+                -0.5075383431387424,
+                0.6607947689962907,
+                -0.053006003483502394,
+                0.019636629690525893,
+            ],
+        );
+
+        // Half-hourly timestamps double the slope per hour and its standard error.
+        let half_hourly: Vec<f64> = (0..23).map(|i| 1800.0 * i as f64).collect();
+        let timed = TimeSeries::new(&series).with_timestamps(Timestamps::Seconds(&half_hourly));
+        let result = feature.apply_timed(&timed);
+        assert_regression(
+            &result,
+            [
+                0.013429423358183555,
+                -0.5075383431387424,
+                0.6607947689962907,
+                -0.10601200696700479,
+                0.039273259381051786,
+            ],
+        );
+
+        // Irregular timestamps.
+        let seconds = [0.0, 600.0, 7200.0, 9000.0, 18000.0];
+        let values = [1.0, 1.5, 2.0, 3.5, 4.0];
+        let timed = TimeSeries::new(&values).with_timestamps(Timestamps::Seconds(&seconds));
+        let result = feature.apply_timed(&timed);
+        let hours = [0.0, 1.0 / 6.0, 2.0, 2.5, 5.0];
+        let expected = linregress(&hours, &values).unwrap();
+        assert_float_eq!(result[3].value, expected.slope);
+        assert_float_eq!(result[2].value, expected.intercept);
+    }
+
+    #[test]
+    fn test_linear_trend_timewise_without_timestamps() {
+        let series = synthetic_series(23);
+        let feature = LinearTrendTimewise::new(ALL_ATTRIBUTES.to_vec());
+        assert!(feature.apply(&series).iter().all(|r| r.value.is_nan()));
+        let result = feature.apply_timed(&TimeSeries::new(&series));
+        assert!(result.iter().all(|r| r.value.is_nan()));
+
+        let short = [0.0, 3600.0];
+        let timed = TimeSeries::new(&series).with_timestamps(Timestamps::Seconds(&short));
+        assert!(feature.apply_timed(&timed).iter().all(|r| r.value.is_nan()));
+    }
+
+    #[test]
+    fn test_agg_linear_trend() {
+        let series = synthetic_series(23);
+        let cases = [
+            (
+                5,
+                Aggregation::Max,
+                [
+                    0.1874253916695476,
+                    -0.7007647609304591,
+                    0.9712097690302002,
+                    -0.2400426101512752,
+                    0.1410860099119939,
+                ],
+            ),
+            (
+                5,
+                Aggregation::Mean,
+                [
+                    0.4620574901065892,
+                    -0.4368181274197817,
+                    0.4773922576177445,
+                    -0.18617401811747053,
+                    0.22135178319064017,
+                ],
+            ),
+            (
+                3,
+                Aggregation::Var,
+                [
+                    0.571318167016836,
+                    0.23739911598009192,
+                    0.0624664304902601,
+                    0.007103125828072994,
+                    0.011865835911389157,
+                ],
+            ),
+            (
+                10,
+                Aggregation::Min,
+                [
+                    0.9980354087016847,
+                    -0.003085967896982688,
+                    -0.1580672615344715,
+                    -0.0028157799165325352,
+                    0.9124419316348356,
+                ],
+            ),
+        ];
+        for (chunk_len, f_agg, expected) in cases {
+            let params = ALL_ATTRIBUTES
+                .iter()
+                .map(|&attr| (attr, chunk_len, f_agg))
+                .collect();
+            let result = AggLinearTrend::new(params).apply(&series);
+            assert_regression(&result, expected);
+        }
+
+        let result = AggLinearTrend::new(vec![
+            (TrendAttribute::Slope, 5, Aggregation::Max),
+            (TrendAttribute::Slope, 23, Aggregation::Max),
+            (TrendAttribute::Slope, 0, Aggregation::Max),
+        ])
+        .apply(&series);
+        assert_eq!(
+            result[0].name,
+            "agg_linear_trend__attr_\"slope\"__chunk_len_5__f_agg_\"max\""
+        );
+        assert!(result[1].value.is_nan());
+        assert!(result[2].value.is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+//! Wavelet feature extraction module.
+//!
+//! Provides features based on the continuous wavelet transform with the Ricker
+//! ("Mexican hat") wavelet. Contains both functional and OOP APIs.
+
+mod functional;
+mod oop;
+
+// Re-export common items for convenience
+pub use functional::{cwt, find_peaks_cwt, number_cwt_peaks, ricker, ricker_cwt_coefficients};
+pub use oop::{NumberCwtPeaks, RickerCwtCoefficients};
@@ -0,0 +1,208 @@
+//! Functional API for wavelet feature extraction.
+
+use crate::features::distribution::quantile_sorted;
+
+/// Samples the Ricker wavelet of width `a` at `points` equally spaced positions
+/// centred around zero.
+pub fn ricker(points: usize, a: f64) -> Vec<f64> {
+    let amplitude = 2.0 / ((3.0 * a).sqrt() * std::f64::consts::PI.powf(0.25));
+    let wsq = a * a;
+    let center = (points as f64 - 1.0) / 2.0;
+    (0..points)
+        .map(|i| {
+            let xsq = (i as f64 - center).powi(2);
+            amplitude * (1.0 - xsq / wsq) * (-xsq / (2.0 * wsq)).exp()
+        })
+        .collect()
+}
+
+/// Computes the continuous wavelet transform of a time series with the Ricker wavelet.
+///
+/// Returns one row per entry of `widths`, each as long as the series. Every row
+/// is the series convolved (same-size, centred) with a Ricker wavelet of
+/// `min(10 * width, len)` points, like scipy's `cwt`.
+pub fn cwt(series: &[f64], widths: &[usize]) -> Vec<Vec<f64>> {
+    let n = series.len();
+    widths
+        .iter()
+        .map(|&width| {
+            if width == 0 {
+                return vec![f64::NAN; n];
+            }
+            let points = (10 * width).min(n);
+            let wavelet = ricker(points, width as f64);
+            let shift = points.saturating_sub(1) / 2;
+            (0..n)
+                .map(|i| {
+                    // Entry `i + shift` of the full convolution.
+                    let k = i + shift;
+                    let start = k.saturating_sub(points - 1);
+                    let end = k.min(n - 1);
+                    (start..=end)
+                        .map(|j| series[j] * wavelet[points - 1 - (k - j)])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A ridge line through the CWT matrix: visited rows and columns, and the
+/// number of rows since it was last extended.
+struct RidgeLine {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    gap: usize,
+}
+
+/// Finds the columns of the strict relative maxima within a row.
+fn relative_maxima(row: &[f64]) -> Vec<usize> {
+    (1..row.len().saturating_sub(1))
+        .filter(|&i| row[i] > row[i - 1] && row[i] > row[i + 1])
+        .collect()
+}
+
+/// Connects relative maxima across CWT rows into ridge lines, starting from
+/// the largest width, like scipy's `_identify_ridge_lines`.
+fn identify_ridge_lines(
+    matrix: &[Vec<f64>],
+    max_distances: &[f64],
+    gap_thresh: usize,
+) -> Vec<RidgeLine> {
+    let all_max_cols: Vec<Vec<usize>> = matrix.iter().map(|row| relative_maxima(row)).collect();
+    let Some(start_row) = all_max_cols.iter().rposition(|cols| !cols.is_empty()) else {
+        return Vec::new();
+    };
+
+    let mut ridge_lines: Vec<RidgeLine> = all_max_cols[start_row]
+        .iter()
+        .map(|&col| RidgeLine {
+            rows: vec![start_row],
+            cols: vec![col],
+            gap: 0,
+        })
+        .collect();
+    let mut final_lines = Vec::new();
+
+    for row in (0..start_row).rev() {
+        for line in ridge_lines.iter_mut() {
+            line.gap += 1;
+        }
+        let prev_ridge_cols: Vec<usize> = ridge_lines
+            .iter()
+            .map(|line| *line.cols.last().unwrap())
+            .collect();
+        for &col in &all_max_cols[row] {
+            // Attach to the first closest ridge line within reach, if any.
+            let closest = prev_ridge_cols
+                .iter()
+                .enumerate()
+                .map(|(i, &prev)| (i, col.abs_diff(prev)))
+                .min_by_key(|&(i, diff)| (diff, i))
+                .filter(|&(_, diff)| diff as f64 <= max_distances[row]);
+            match closest {
+                Some((i, _)) => {
+                    let line = &mut ridge_lines[i];
+                    line.rows.push(row);
+                    line.cols.push(col);
+                    line.gap = 0;
+                }
+                None => ridge_lines.push(RidgeLine {
+                    rows: vec![row],
+                    cols: vec![col],
+                    gap: 0,
+                }),
+            }
+        }
+        for i in (0..ridge_lines.len()).rev() {
+            if ridge_lines[i].gap > gap_thresh {
+                final_lines.push(ridge_lines.remove(i));
+            }
+        }
+    }
+
+    final_lines.extend(ridge_lines);
+    for line in final_lines.iter_mut() {
+        // Same reordering as scipy: `rows[argsort(rows)] = rows`.
+        let mut order: Vec<usize> = (0..line.rows.len()).collect();
+        order.sort_by_key(|&i| line.rows[i]);
+        let mut rows = vec![0; order.len()];
+        let mut cols = vec![0; order.len()];
+        for (i, &target) in order.iter().enumerate() {
+            rows[target] = line.rows[i];
+            cols[target] = line.cols[i];
+        }
+        line.rows = rows;
+        line.cols = cols;
+    }
+    final_lines
+}
+
+/// Finds peaks of a time series with the CWT ridge-line method of scipy's
+/// `find_peaks_cwt`, using its default tuning parameters.
+///
+/// Returns the sorted indices of the detected peaks.
+pub fn find_peaks_cwt(series: &[f64], widths: &[usize]) -> Vec<usize> {
+    if series.is_empty() || widths.is_empty() {
+        return Vec::new();
+    }
+    let matrix = cwt(series, widths);
+    let max_distances: Vec<f64> = widths.iter().map(|&w| w as f64 / 4.0).collect();
+    let ridge_lines = identify_ridge_lines(&matrix, &max_distances, widths[0]);
+
+    let num_points = series.len();
+    let min_length = widths.len().div_ceil(4);
+    let window_size = num_points.div_ceil(20);
+    let (hf_window, odd) = (window_size / 2, window_size % 2);
+    let row_one = &matrix[0];
+    let noises: Vec<f64> = (0..num_points)
+        .map(|i| {
+            let start = i.saturating_sub(hf_window);
+            let end = (i + hf_window + odd).min(num_points);
+            let mut window = row_one[start..end].to_vec();
+            window.sort_by(f64::total_cmp);
+            quantile_sorted(&window, 0.1)
+        })
+        .collect();
+
+    let min_snr = 1.0;
+    let mut peaks: Vec<usize> = ridge_lines
+        .iter()
+        .filter(|line| {
+            if line.rows.len() < min_length {
+                return false;
+            }
+            let snr = (matrix[line.rows[0]][line.cols[0]] / noises[line.cols[0]]).abs();
+            // A NaN ratio passes the filter, as in scipy.
+            snr.is_nan() || snr >= min_snr
+        })
+        .map(|line| line.cols[0])
+        .collect();
+    peaks.sort_unstable();
+    peaks
+}
+
+/// Calculates the CWT coefficient at position `coeff` for the width `w` out of `widths`.
+///
+/// The transform convolves with the Ricker wavelet like scipy's former
+/// `signal.cwt`. tsfresh's `cwt_coefficients` uses `pywt.cwt(..., "mexh")`
+/// instead, which integrates and scales the Mexican hat wavelet differently,
+/// so this feature has its own name.
+///
+/// Returns NaN if `w` is not one of `widths` or `coeff` is out of range.
+pub fn ricker_cwt_coefficients(series: &[f64], widths: &[usize], coeff: usize, w: usize) -> f64 {
+    match widths.iter().position(|&width| width == w) {
+        // Rows of the transform are independent, so only the row for `w` is needed.
+        Some(i) => cwt(series, &widths[i..=i])[0]
+            .get(coeff)
+            .copied()
+            .unwrap_or(f64::NAN),
+        None => f64::NAN,
+    }
+}
+
+/// Counts the peaks found by [`find_peaks_cwt`] for widths `1..=n`.
+pub fn number_cwt_peaks(series: &[f64], n: usize) -> usize {
+    let widths: Vec<usize> = (1..=n).collect();
+    find_peaks_cwt(series, &widths).len()
+}
@@ -0,0 +1,140 @@
+//! OOP API for wavelet feature extraction.
+
+use crate::features::common::{FeatureFunction, FeatureFunctionReturn, format_feature_name};
+
+use super::functional::{cwt, number_cwt_peaks};
+
+/// Feature function that reports coefficients of the Ricker wavelet transform.
+///
+/// This is not tsfresh's `cwt_coefficients`, which uses pywt's Mexican hat
+/// transform; see [`ricker_cwt_coefficients`](super::ricker_cwt_coefficients).
+///
+/// Each parameter is a `(widths, coeff, w)` triple selecting position `coeff`
+/// of the row for width `w`. The transform is computed once per series for all
+/// distinct requested widths.
+pub struct RickerCwtCoefficients {
+    pub params: Vec<(Vec<usize>, usize, usize)>,
+}
+
+impl RickerCwtCoefficients {
+    pub fn new(params: Vec<(Vec<usize>, usize, usize)>) -> Self {
+        Self { params }
+    }
+}
+
+impl FeatureFunction for RickerCwtCoefficients {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        let mut widths: Vec<usize> = self
+            .params
+            .iter()
+            .filter(|(widths, _, w)| widths.contains(w))
+            .map(|&(_, _, w)| w)
+            .collect();
+        widths.sort_unstable();
+        widths.dedup();
+        let matrix = cwt(series, &widths);
+
+        self.params
+            .iter()
+            .map(|(param_widths, coeff, w)| {
+                let value = match widths.binary_search(w) {
+                    Ok(row) if param_widths.contains(w) => {
+                        matrix[row].get(*coeff).copied().unwrap_or(f64::NAN)
+                    }
+                    _ => f64::NAN,
+                };
+                FeatureFunctionReturn {
+                    name: format_feature_name(
+                        "ricker_cwt_coefficients",
+                        &[("coeff", coeff), ("w", w), ("widths", param_widths)],
+                    ),
+                    value,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Feature function that counts the peaks detected by the CWT for widths `1..=n`.
+pub struct NumberCwtPeaks {
+    pub n: usize,
+}
+
+impl NumberCwtPeaks {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+impl FeatureFunction for NumberCwtPeaks {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        vec![FeatureFunctionReturn {
+            name: format_feature_name("number_cwt_peaks", &[("n", &self.n)]),
+            value: number_cwt_peaks(series, self.n) as f64,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_float_eq, features::wavelet::find_peaks_cwt, test_utils::synthetic_series};
+
+    #[test]
+    fn test_ricker_cwt_coefficients() {
+        let series = synthetic_series(60);
+        let widths = vec![2, 5, 10, 20];
+        let result = RickerCwtCoefficients::new(vec![
+            (widths.clone(), 0, 2),
+            (widths.clone(), 3, 5),
+            (widths.clone(), 10, 10),
+            (widths.clone(), 59, 20),
+            (widths.clone(), 60, 20),
+            (widths.clone(), 0, 3),
+        ])
+        .apply(&series);
+        assert_eq!(
+            result[0].name,
+            "ricker_cwt_coefficients__coeff_0__w_2__widths_(2, 5, 10, 20)"
+        );
+        assert_float_eq!(result[0].value, -0.10050411546039392);
+        assert_float_eq!(result[1].value, 1.719484568494981);
+        assert_float_eq!(result[2].value, -0.3698793329573841);
+        assert_float_eq!(result[3].value, 0.743956257555813);
+        assert!(result[4].value.is_nan());
+        assert!(result[5].value.is_nan());
+    }
+
+    #[test]
+    fn test_find_peaks_cwt() {
+        let series = synthetic_series(60);
+        assert_eq!(
+            find_peaks_cwt(&series, &[1, 2, 3]),
+            [
+                1, 5, 7, 8, 9, 12, 21, 25, 27, 29, 32, 44, 47, 48, 49, 50, 53, 57
+            ]
+        );
+        let widths: Vec<usize> = (1..=10).collect();
+        assert_eq!(find_peaks_cwt(&series, &widths), [7, 26, 47]);
+    }
+
+    #[test]
+    fn test_number_cwt_peaks() {
+        let series = synthetic_series(60);
+        let result = NumberCwtPeaks::new(1).apply(&series);
+        assert_eq!(result[0].name, "number_cwt_peaks__n_1");
+        assert_float_eq!(result[0].value, 13.0);
+
+        let result = NumberCwtPeaks::new(5).apply(&series);
+        assert_float_eq!(result[0].value, 6.0);
+
+        let empty: [f64; 0] = [];
+        let result = NumberCwtPeaks::new(5).apply(&empty);
+        assert_float_eq!(result[0].value, 0.0);
+        // NaN must not panic while sorting the noise windows.
+        let mut series = series;
+        series[30] = f64::NAN;
+        let result = NumberCwtPeaks::new(5).apply(&series);
+        assert!(result[0].value.is_finite());
+    }
+}
@@ -1,6 +1,11 @@
 pub mod extractor;
 pub mod features;
+pub(crate) mod math;
+pub mod settings;
 
-pub use extractor::extract_features;
+pub use extractor::{
+    extract_features, extract_features_timed, extract_features_with_column_settings,
+    extract_features_with_settings,
+};
 #[cfg(test)]
 pub mod test_utils;
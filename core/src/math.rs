@@ -0,0 +1,9 @@
+//! Numerical helpers shared by the feature modules.
+
+mod linalg;
+mod polynomial;
+mod special;
+
+pub(crate) use linalg::{LeastSquares, least_squares};
+pub(crate) use polynomial::{polyfit, polynomial_roots};
+pub(crate) use special::{normal_cdf, student_t_sf};
@@ -0,0 +1,143 @@
+//! Ordinary least squares via Householder QR decomposition.
+
+/// Result of an ordinary least squares fit.
+pub(crate) struct LeastSquares {
+    pub coefficients: Vec<f64>,
+    /// Sum of squared residuals.
+    pub ssr: f64,
+    /// Diagonal of `(XᵀX)⁻¹`.
+    pub unscaled_variances: Vec<f64>,
+    pub nobs: usize,
+}
+
+impl LeastSquares {
+    /// Standard errors of the coefficients.
+    pub fn standard_errors(&self) -> Vec<f64> {
+        let dof = self.nobs as f64 - self.coefficients.len() as f64;
+        let scale = self.ssr / dof;
+        self.unscaled_variances
+            .iter()
+            .map(|v| (v * scale).sqrt())
+            .collect()
+    }
+
+    /// t statistics of the coefficients.
+    pub fn t_values(&self) -> Vec<f64> {
+        self.coefficients
+            .iter()
+            .zip(self.standard_errors())
+            .map(|(c, se)| c / se)
+            .collect()
+    }
+
+    /// Gaussian log-likelihood of the fit, as reported by statsmodels.
+    pub fn log_likelihood(&self) -> f64 {
+        let n = self.nobs as f64;
+        -n / 2.0 * (2.0 * std::f64::consts::PI * self.ssr / n).ln() - n / 2.0
+    }
+}
+
+/// Fits `y ≈ X β` by least squares, with `X` given as a list of columns.
+///
+/// Returns `None` if there are fewer observations than columns or the design
+/// matrix is (numerically) rank deficient.
+pub(crate) fn least_squares(columns: &[Vec<f64>], y: &[f64]) -> Option<LeastSquares> {
+    let n = y.len();
+    let k = columns.len();
+    if k == 0 || n < k || columns.iter().any(|c| c.len() != n) {
+        return None;
+    }
+
+    // Householder QR, transforming `a` into R (upper part) and `qty` into Qᵀy.
+    let mut a: Vec<Vec<f64>> = columns.to_vec();
+    let mut qty = y.to_vec();
+    let mut max_diag: f64 = 0.0;
+    for j in 0..k {
+        let norm = a[j][j..].iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return None;
+        }
+        let alpha = if a[j][j] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = a[j][j..].to_vec();
+        v[0] -= alpha;
+        let v_norm_sq: f64 = v.iter().map(|x| x * x).sum();
+        if v_norm_sq > 0.0 {
+            for column in a[j..].iter_mut().chain(std::iter::once(&mut qty)) {
+                let dot: f64 = v.iter().zip(&column[j..]).map(|(p, q)| p * q).sum();
+                let factor = 2.0 * dot / v_norm_sq;
+                for (c, p) in column[j..].iter_mut().zip(&v) {
+                    *c -= factor * p;
+                }
+            }
+        }
+        max_diag = max_diag.max(a[j][j].abs());
+    }
+    let tolerance = max_diag * n as f64 * f64::EPSILON;
+    if (0..k).any(|j| a[j][j].abs() <= tolerance) {
+        return None;
+    }
+
+    // Back substitution for β and for the rows of R⁻¹.
+    let r = |row: usize, col: usize| a[col][row];
+    let mut coefficients = vec![0.0; k];
+    for i in (0..k).rev() {
+        let s: f64 = (i + 1..k).map(|j| r(i, j) * coefficients[j]).sum();
+        coefficients[i] = (qty[i] - s) / r(i, i);
+    }
+    // R⁻¹ is stored column by column.
+    let mut r_inv = vec![vec![0.0; k]; k];
+    for (col, inv_col) in r_inv.iter_mut().enumerate() {
+        inv_col[col] = 1.0 / r(col, col);
+        for i in (0..col).rev() {
+            let s: f64 = (i + 1..=col).map(|j| r(i, j) * inv_col[j]).sum();
+            inv_col[i] = -s / r(i, i);
+        }
+    }
+    let unscaled_variances = (0..k)
+        .map(|i| r_inv.iter().map(|inv_col| inv_col[i] * inv_col[i]).sum())
+        .collect();
+
+    let ssr = (0..n)
+        .map(|i| {
+            let fitted: f64 = columns
+                .iter()
+                .zip(&coefficients)
+                .map(|(c, b)| c[i] * b)
+                .sum();
+            (y[i] - fitted).powi(2)
+        })
+        .sum();
+
+    Some(LeastSquares {
+        coefficients,
+        ssr,
+        unscaled_variances,
+        nobs: n,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_least_squares_line() {
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = [1.0, 3.1, 4.9, 7.2, 8.8];
+        let fit = least_squares(&[vec![1.0; 5], x], &y).unwrap();
+        assert_float_eq!(fit.coefficients[0], 1.06);
+        assert_float_eq!(fit.coefficients[1], 1.97);
+        assert_float_eq!(fit.ssr, 0.091);
+        assert_float_eq!(fit.unscaled_variances[1], 0.1);
+        assert_float_eq!(fit.unscaled_variances[0], 0.6);
+    }
+
+    #[test]
+    fn test_least_squares_rank_deficient() {
+        let x = vec![1.0, 2.0, 3.0];
+        let y = [1.0, 2.0, 3.0];
+        assert!(least_squares(&[x.clone(), x], &y).is_none());
+        assert!(least_squares(&[vec![1.0]], &[1.0, 2.0]).is_none());
+    }
+}
@@ -0,0 +1,149 @@
+//! Polynomial least-squares fitting and root finding.
+
+use rustfft::num_complex::Complex;
+
+use super::least_squares;
+
+/// Fits a polynomial of the given degree to the points `(x, y)` by least squares.
+///
+/// Returns the coefficients with the highest power first, like `numpy.polyfit`,
+/// or `None` if there are fewer distinct points than coefficients. Columns of
+/// the Vandermonde matrix are scaled to unit norm to improve conditioning.
+pub(crate) fn polyfit(x: &[f64], y: &[f64], degree: usize) -> Option<Vec<f64>> {
+    let mut scales = Vec::with_capacity(degree + 1);
+    let columns: Vec<Vec<f64>> = (0..=degree)
+        .map(|j| {
+            let power = (degree - j) as i32;
+            let column: Vec<f64> = x.iter().map(|v| v.powi(power)).collect();
+            let norm = column.iter().map(|v| v * v).sum::<f64>().sqrt();
+            let scale = if norm == 0.0 { 1.0 } else { norm };
+            scales.push(scale);
+            column.into_iter().map(|v| v / scale).collect()
+        })
+        .collect();
+    let fit = least_squares(&columns, y)?;
+    Some(
+        fit.coefficients
+            .iter()
+            .zip(&scales)
+            .map(|(c, scale)| c / scale)
+            .collect(),
+    )
+}
+
+/// Evaluates a polynomial and its derivative at `z` with Horner's scheme.
+fn evaluate_with_derivative(coefficients: &[f64], z: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+    let mut value = Complex::new(0.0, 0.0);
+    let mut derivative = Complex::new(0.0, 0.0);
+    for &c in coefficients {
+        derivative = derivative * z + value;
+        value = value * z + c;
+    }
+    (value, derivative)
+}
+
+/// Finds all complex roots of a polynomial given with the highest power first.
+///
+/// Like `numpy.roots`, leading zeros are ignored and trailing zeros yield roots
+/// at zero. The remaining roots are found simultaneously with the Aberth-Ehrlich
+/// iteration. Returns an empty vector for constant polynomials.
+pub(crate) fn polynomial_roots(coefficients: &[f64]) -> Vec<Complex<f64>> {
+    let Some(first) = coefficients.iter().position(|&c| c != 0.0) else {
+        return Vec::new();
+    };
+    let last = coefficients.iter().rposition(|&c| c != 0.0).unwrap();
+    let monic: Vec<f64> = coefficients[first..=last]
+        .iter()
+        .map(|c| c / coefficients[first])
+        .collect();
+    let degree = monic.len() - 1;
+    let zero_roots = coefficients.len() - 1 - last;
+
+    // Start on a circle enclosing all roots (Fujiwara's bound), with an angular
+    // offset so that no initial guess is real.
+    let radius = monic[1..]
+        .iter()
+        .enumerate()
+        .map(|(i, c)| c.abs().powf(1.0 / (i + 1) as f64))
+        .fold(0.0, f64::max)
+        * 2.0;
+    let mut roots: Vec<Complex<f64>> = (0..degree)
+        .map(|k| {
+            let angle = 2.0 * std::f64::consts::PI * k as f64 / degree as f64 + 0.4;
+            Complex::from_polar(radius.max(f64::MIN_POSITIVE), angle)
+        })
+        .collect();
+    for _ in 0..500 {
+        let mut max_step: f64 = 0.0;
+        for k in 0..degree {
+            let (value, derivative) = evaluate_with_derivative(&monic, roots[k]);
+            if value == Complex::new(0.0, 0.0) {
+                continue;
+            }
+            let ratio = value / derivative;
+            let repulsion: Complex<f64> = (0..degree)
+                .filter(|&j| j != k)
+                .map(|j| (roots[k] - roots[j]).inv())
+                .sum();
+            let step = ratio / (Complex::new(1.0, 0.0) - ratio * repulsion);
+            roots[k] -= step;
+            max_step = max_step.max(step.norm() / roots[k].norm().max(1.0));
+        }
+        if !max_step.is_finite() || max_step < 1e-15 {
+            break;
+        }
+    }
+    roots.extend(std::iter::repeat_n(Complex::new(0.0, 0.0), zero_roots));
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_polyfit_exact() {
+        let x: Vec<f64> = (0..8).map(|i| i as f64 * 0.5 - 1.0).collect();
+        let y: Vec<f64> = x.iter().map(|v| 2.0 * v * v * v - v + 0.5).collect();
+        let coefficients = polyfit(&x, &y, 3).unwrap();
+        for (c, expected) in coefficients.iter().zip([2.0, 0.0, -1.0, 0.5]) {
+            assert_float_eq!(c, expected, 1e-12);
+        }
+        assert!(polyfit(&x[..3], &y[..3], 3).is_none());
+    }
+
+    #[test]
+    fn test_polynomial_roots() {
+        // (x - 1)(x - 2)(x + 3) = x³ - 7x + 6
+        let mut roots: Vec<f64> = polynomial_roots(&[1.0, 0.0, -7.0, 6.0])
+            .iter()
+            .map(|z| {
+                assert_float_eq!(z.im, 0.0, 1e-12);
+                z.re
+            })
+            .collect();
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (root, expected) in roots.iter().zip([-3.0, 1.0, 2.0]) {
+            assert_float_eq!(root, expected, 1e-12);
+        }
+
+        // Leading zeros are dropped and trailing zeros give roots at zero: 2x³ + 2x.
+        let roots = polynomial_roots(&[0.0, 2.0, 0.0, 2.0, 0.0]);
+        assert_eq!(roots.len(), 3);
+        assert!(roots.iter().any(|z| z.norm() < 1e-12));
+        assert!(
+            roots
+                .iter()
+                .any(|z| (z - Complex::new(0.0, 1.0)).norm() < 1e-12)
+        );
+        assert!(
+            roots
+                .iter()
+                .any(|z| (z - Complex::new(0.0, -1.0)).norm() < 1e-12)
+        );
+
+        assert!(polynomial_roots(&[3.0]).is_empty());
+        assert!(polynomial_roots(&[0.0, 0.0]).is_empty());
+    }
+}
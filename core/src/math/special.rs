@@ -0,0 +1,169 @@
+//! Special functions used by statistical tests.
+
+/// Complementary error function.
+///
+/// Uses the positive-term series of `erf` for small arguments and a continued
+/// fraction for the tail, giving close to double precision everywhere.
+fn erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        return 2.0 - erfc(-x);
+    }
+    if x < 3.0 {
+        // erf(x) = 2/√π · e^{-x²} · Σ 2ⁿ x^{2n+1} / (1·3·…·(2n+1))
+        let mut term = x;
+        let mut sum = x;
+        let mut n = 0.0;
+        while term > sum * 1e-17 {
+            n += 1.0;
+            term *= 2.0 * x * x / (2.0 * n + 1.0);
+            sum += term;
+        }
+        1.0 - 2.0 / std::f64::consts::PI.sqrt() * (-x * x).exp() * sum
+    } else {
+        // erfc(x) = e^{-x²}/√π · 1/(x + (1/2)/(x + 1/(x + (3/2)/(x + …)))), via Lentz's method.
+        let tiny = 1e-300;
+        let mut f = x;
+        let mut c = x;
+        let mut d = 0.0;
+        for i in 1..500 {
+            let a = i as f64 / 2.0;
+            d = x + a * d;
+            d = if d.abs() < tiny { tiny } else { d };
+            c = x + a / c;
+            c = if c.abs() < tiny { tiny } else { c };
+            d = 1.0 / d;
+            let delta = c * d;
+            f *= delta;
+            if (delta - 1.0).abs() < 1e-16 {
+                break;
+            }
+        }
+        (-x * x).exp() / (f * std::f64::consts::PI.sqrt())
+    }
+}
+
+/// Cumulative distribution function of the standard normal distribution.
+pub(crate) fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Natural logarithm of the gamma function, via the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula: Γ(x) Γ(1 - x) = π / sin(πx).
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).abs().ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |acc, (i, c)| {
+            acc + c / (x + i as f64 + 1.0)
+        });
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`.
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    // The continued fraction converges quickly only below the mean of the
+    // distribution, so use the symmetry I_x(a, b) = 1 - I_{1-x}(b, a) above it.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction of the incomplete beta function, evaluated with Lentz's method.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let tiny = 1e-300;
+    let clamp = |v: f64| if v.abs() < tiny { tiny } else { v };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut f = d;
+    for m in 1..1000 {
+        let m = m as f64;
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / clamp(1.0 + even * d);
+        c = clamp(1.0 + even / c);
+        f *= c * d;
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / clamp(1.0 + odd * d);
+        c = clamp(1.0 + odd / c);
+        let delta = c * d;
+        f *= delta;
+        if (delta - 1.0).abs() < 1e-16 {
+            break;
+        }
+    }
+    f
+}
+
+/// Survival function `P(T > t)` of Student's t distribution with `df` degrees of freedom.
+pub(crate) fn student_t_sf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * regularized_incomplete_beta(df / 2.0, 0.5, df / (df + t * t));
+    if t > 0.0 { tail } else { 1.0 - tail }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_normal_cdf() {
+        assert_float_eq!(normal_cdf(0.0), 0.5);
+        assert_float_eq!(normal_cdf(1.0), 0.8413447460685429, 1e-14);
+        assert_float_eq!(normal_cdf(-1.96), 0.024997895148220435, 1e-14);
+        assert_float_eq!(normal_cdf(3.5), 0.9997673709209645, 1e-14);
+        assert_float_eq!(normal_cdf(-6.0) / 9.865876450377012e-10, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_float_eq!(ln_gamma(1.0), 0.0, 1e-14);
+        assert_float_eq!(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-14);
+        assert_float_eq!(ln_gamma(10.0), 362880f64.ln(), 1e-12);
+        assert_float_eq!(ln_gamma(0.1), 2.252712651734206, 1e-13);
+    }
+
+    #[test]
+    fn test_student_t_sf() {
+        // Closed forms for one (Cauchy) and two degrees of freedom.
+        for t in [-3.0, -0.5, 0.0, 0.7, 2.0, 25.0] {
+            let cauchy = 0.5 - f64::atan(t) / std::f64::consts::PI;
+            assert_float_eq!(student_t_sf(t, 1.0), cauchy, 1e-14);
+            let two = 0.5 - t / (2.0 * (2.0 + t * t).sqrt());
+            assert_float_eq!(student_t_sf(t, 2.0), two, 1e-14);
+        }
+        assert_float_eq!(student_t_sf(2.0, 10.0), 0.03669401738537019, 1e-14);
+        assert_float_eq!(student_t_sf(8.0, 30.0) / 3.132911237850382e-9, 1.0, 1e-10);
+    }
+}
@@ -0,0 +1,1834 @@
+//! Feature settings, the equivalent of tsfresh's `fc_parameters` dictionaries.
+//!
+//! [`FeatureSettings`] maps feature names to lists of parameter sets and can be
+//! built into a [`FeatureSet`] that computes all configured features. The
+//! presets [`FeatureSettings::minimal`], [`FeatureSettings::efficient`] and
+//! [`FeatureSettings::comprehensive`] mirror tsfresh's `MinimalFCParameters`,
+//! `EfficientFCParameters` and `ComprehensiveFCParameters`.
+//!
+//! [`ColumnSettings`] assigns different settings to individual columns, like
+//! tsfresh's `kind_to_fc_parameters`. Both can be reconstructed from extracted
+//! feature names with [`FeatureSettings::from_names`] and
+//! [`ColumnSettings::from_columns`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::features::{
+    autocorrelation::{
+        AggAutocorrelation, ArCoefficient, ArEstimator, Autocorrelation, PartialAutocorrelation,
+    },
+    change::{AbsoluteSumOfChanges, CidCe, MeanAbsChange, MeanChange, MeanSecondDerivativeCentral},
+    common::{
+        Aggregation, FeatureFunction, FeatureFunctionReturn, FormatParam, Number, TimeSeries,
+    },
+    counting::{
+        CountAbove, CountAboveMean, CountBelow, CountBelowMean, LongestStrikeAboveMean,
+        LongestStrikeBelowMean, NumberCrossingM, NumberPeaks, RangeCount, ValueCount,
+    },
+    distribution::{
+        BenfordCorrelation, ChangeQuantiles, IndexMassQuantile, Kurtosis, LargeStandardDeviation,
+        Quantile, RatioBeyondRSigma, Skewness, SymmetryLooking,
+        VarianceLargerThanStandardDeviation, VariationCoefficient, is_unit_interval,
+    },
+    energy::{AbsEnergy, EnergyRatioByChunks},
+    entropy::{
+        ApproximateEntropy, BinnedEntropy, FourierEntropy, LempelZivComplexity, PermutationEntropy,
+        SampleEntropy,
+    },
+    extrema::{
+        FirstLocationOfMaximum, FirstLocationOfMinimum, HasDuplicate, HasDuplicateMax,
+        HasDuplicateMin, LastLocationOfMaximum, LastLocationOfMinimum, MeanNAbsoluteMax,
+    },
+    langevin::{FriedrichCoefficients, MaxLangevinFixedPoint},
+    matrix_profile::{MatrixProfile, MatrixProfileStatistic, QuerySimilarityCount, query_hash},
+    minimal::{
+        AbsoluteMaximum, Length, Maximum, Mean, Median, Minimum, RootMeanSquare, StandardDeviation,
+        SumValues, Variance,
+    },
+    nonlinearity::{C3, TimeReversalAsymmetryStatistic},
+    recurring::{
+        PercentageOfReoccurringDatapointsToAllDatapoints, PercentageOfReoccurringValuesToAllValues,
+        RatioValueNumberToTimeSeriesLength, SumOfReoccurringDataPoints, SumOfReoccurringValues,
+    },
+    spectral::{
+        FftAggType, FftAggregated, FftAttribute, FftCoefficient, SpktWelchDensity, WelchConfig,
+        WelchWindow,
+    },
+    stationarity::{AdfAttribute, AugmentedDickeyFuller, Autolag},
+    trend::{AggLinearTrend, LinearTrend, LinearTrendTimewise, TrendAttribute},
+    wavelet::{NumberCwtPeaks, RickerCwtCoefficients},
+};
+
+/// A parameter value, the Rust counterpart of the values in tsfresh's
+/// parameter dictionaries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Param {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    /// A tuple of integers such as the `widths` of `ricker_cwt_coefficients`.
+    Tuple(Vec<usize>),
+    /// A sequence of values such as the `query` of `query_similarity_count`.
+    Series(Vec<f64>),
+    /// Python's `None`.
+    None,
+}
+
+impl From<i32> for Param {
+    fn from(value: i32) -> Self {
+        Param::Int(value.into())
+    }
+}
+
+impl From<i64> for Param {
+    fn from(value: i64) -> Self {
+        Param::Int(value)
+    }
+}
+
+impl From<f64> for Param {
+    fn from(value: f64) -> Self {
+        Param::Float(value)
+    }
+}
+
+impl From<bool> for Param {
+    fn from(value: bool) -> Self {
+        Param::Bool(value)
+    }
+}
+
+impl From<&str> for Param {
+    fn from(value: &str) -> Self {
+        Param::Str(value.to_string())
+    }
+}
+
+impl From<String> for Param {
+    fn from(value: String) -> Self {
+        Param::Str(value)
+    }
+}
+
+impl From<Vec<usize>> for Param {
+    fn from(value: Vec<usize>) -> Self {
+        Param::Tuple(value)
+    }
+}
+
+impl From<Vec<f64>> for Param {
+    fn from(value: Vec<f64>) -> Self {
+        Param::Series(value)
+    }
+}
+
+impl From<Number> for Param {
+    fn from(value: Number) -> Self {
+        match value {
+            Number::Int(v) => Param::Int(v),
+            Number::Float(v) => Param::Float(v),
+        }
+    }
+}
+
+impl FormatParam for Param {
+    /// Renders the value like the feature names do; a series is rendered as its
+    /// [`query_hash`].
+    fn format_param(&self) -> String {
+        match self {
+            Param::Int(v) => v.format_param(),
+            Param::Float(v) => v.format_param(),
+            Param::Bool(v) => v.format_param(),
+            Param::Str(v) => v.format_param(),
+            Param::Tuple(v) => v.format_param(),
+            Param::Series(v) => query_hash(v).format_param(),
+            Param::None => "None".to_string(),
+        }
+    }
+}
+
+impl Param {
+    /// Parses a value rendered by [`FormatParam`], the inverse of
+    /// [`format_param`](FormatParam::format_param).
+    ///
+    /// A series cannot be recovered from its hash and is parsed as the quoted
+    /// hash string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kalax::settings::Param;
+    ///
+    /// assert_eq!(Param::parse("3"), Some(Param::Int(3)));
+    /// assert_eq!(Param::parse("1e-05"), Some(Param::Float(1e-5)));
+    /// assert_eq!(Param::parse("\"real\""), Some(Param::Str("real".to_string())));
+    /// assert_eq!(Param::parse("(2, 5)"), Some(Param::Tuple(vec![2, 5])));
+    /// assert_eq!(Param::parse("mean"), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Some(s) = value
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            return Some(Param::Str(s.to_string()));
+        }
+        if let Some(items) = value
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let items = items.strip_suffix(',').unwrap_or(items);
+            if items.is_empty() {
+                return Some(Param::Tuple(Vec::new()));
+            }
+            return items
+                .split(", ")
+                .map(|item| item.parse().ok())
+                .collect::<Option<_>>()
+                .map(Param::Tuple);
+        }
+        match value {
+            "True" => return Some(Param::Bool(true)),
+            "False" => return Some(Param::Bool(false)),
+            "None" => return Some(Param::None),
+            "nan" => return Some(Param::Float(f64::NAN)),
+            "inf" => return Some(Param::Float(f64::INFINITY)),
+            "-inf" => return Some(Param::Float(f64::NEG_INFINITY)),
+            _ => {}
+        }
+        // Only accept the digits, signs and exponents Python uses, so that
+        // spellings like "infinity" do not slip through Rust's float parser.
+        if value.is_empty()
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e'))
+        {
+            return None;
+        }
+        value
+            .parse()
+            .map(Param::Int)
+            .or_else(|_| value.parse().map(Param::Float))
+            .ok()
+    }
+}
+
+/// One parameter set of a feature, mapping parameter names to values.
+pub type Params = BTreeMap<String, Param>;
+
+/// Builds a parameter set from `(name, value)` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::settings::{Param, params};
+///
+/// let set = params([("lag", 3.into()), ("f_agg", "mean".into())]);
+/// assert_eq!(set["lag"], Param::Int(3));
+/// ```
+pub fn params<const N: usize>(entries: [(&str, Param); N]) -> Params {
+    entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+/// Splits a feature name like `fft_coefficient__attr_"real"__coeff_3` into
+/// the feature and its parameter set.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::settings::{params, parse_feature_name};
+///
+/// let (feature, set) = parse_feature_name("fft_coefficient__attr_\"real\"__coeff_3").unwrap();
+/// assert_eq!(feature, "fft_coefficient");
+/// assert_eq!(set, params([("attr", "real".into()), ("coeff", 3.into())]));
+/// ```
+pub fn parse_feature_name(name: &str) -> Result<(&str, Params), SettingsError> {
+    let invalid = || SettingsError::InvalidName(name.to_string());
+    let mut parts = name.split("__");
+    let feature = parts.next().filter(|f| !f.is_empty()).ok_or_else(invalid)?;
+    let mut set = Params::new();
+    for part in parts {
+        // Keys contain underscores, values only inside quotes and tuples.
+        let split = if part.ends_with('"') {
+            part.find("_\"")
+        } else if part.ends_with(')') {
+            part.find("_(")
+        } else {
+            part.rfind('_')
+        };
+        let (key, value) = split
+            .map(|i| (&part[..i], &part[i + 1..]))
+            .ok_or_else(invalid)?;
+        let value = Param::parse(value).ok_or_else(invalid)?;
+        if key.is_empty() || set.insert(key.to_string(), value).is_some() {
+            return Err(invalid());
+        }
+    }
+    Ok((feature, set))
+}
+
+/// Error raised when [`FeatureSettings`] cannot be turned into feature functions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingsError {
+    /// A feature name could not be parsed into a feature and its parameters.
+    InvalidName(String),
+    /// The feature name is not known.
+    UnknownFeature(String),
+    /// A required parameter is missing from a parameter set.
+    MissingParameter { feature: String, key: String },
+    /// A parameter set contains a parameter the feature does not take.
+    UnexpectedParameter { feature: String, key: String },
+    /// A parameter has a value of the wrong type or outside its domain.
+    InvalidParameter {
+        feature: String,
+        key: String,
+        value: Param,
+    },
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::InvalidName(name) => write!(f, "cannot parse feature name `{name}`"),
+            SettingsError::UnknownFeature(feature) => write!(f, "unknown feature `{feature}`"),
+            SettingsError::MissingParameter { feature, key } => {
+                write!(f, "feature `{feature}` is missing parameter `{key}`")
+            }
+            SettingsError::UnexpectedParameter { feature, key } => {
+                write!(f, "feature `{feature}` does not take parameter `{key}`")
+            }
+            SettingsError::InvalidParameter {
+                feature,
+                key,
+                value,
+            } => write!(
+                f,
+                "invalid value {} for parameter `{key}` of feature `{feature}`",
+                value.format_param()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+/// Features computed by tsfresh's `MinimalFCParameters`.
+const MINIMAL_FEATURES: [&str; 10] = [
+    "absolute_maximum",
+    "length",
+    "maximum",
+    "mean",
+    "median",
+    "minimum",
+    "root_mean_square",
+    "standard_deviation",
+    "sum_values",
+    "variance",
+];
+
+/// Features without parameters beyond the minimal ones.
+const SIMPLE_FEATURES: [&str; 27] = [
+    "abs_energy",
+    "absolute_sum_of_changes",
+    "benford_correlation",
+    "count_above_mean",
+    "count_below_mean",
+    "first_location_of_maximum",
+    "first_location_of_minimum",
+    "has_duplicate",
+    "has_duplicate_max",
+    "has_duplicate_min",
+    "kurtosis",
+    "last_location_of_maximum",
+    "last_location_of_minimum",
+    "longest_strike_above_mean",
+    "longest_strike_below_mean",
+    "mean_abs_change",
+    "mean_change",
+    "mean_second_derivative_central",
+    "percentage_of_reoccurring_datapoints_to_all_datapoints",
+    "percentage_of_reoccurring_values_to_all_values",
+    "ratio_value_number_to_time_series_length",
+    "sample_entropy",
+    "skewness",
+    "sum_of_reoccurring_data_points",
+    "sum_of_reoccurring_values",
+    "variance_larger_than_standard_deviation",
+    "variation_coefficient",
+];
+
+/// Features flagged with `high_comp_cost` in tsfresh, which
+/// [`FeatureSettings::efficient`] leaves out.
+const HIGH_COMP_COST_FEATURES: [&str; 2] = ["approximate_entropy", "sample_entropy"];
+
+/// Maps feature names to the parameter sets they are computed for.
+///
+/// A parameterless feature is enabled with a single empty parameter set. A
+/// feature with several parameter sets yields one value per set, named like
+/// tsfresh does, e.g. `quantile__q_0.1`.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::features::common::FeatureFunction;
+/// use kalax::settings::{FeatureSettings, params};
+///
+/// let mut settings = FeatureSettings::new();
+/// settings.insert("mean", vec![params([])]);
+/// settings.insert("quantile", vec![params([("q", 0.1.into())]), params([("q", 0.9.into())])]);
+///
+/// let features = settings.build().unwrap().apply(&[1.0, 2.0, 3.0]);
+/// let names: Vec<&str> = features.iter().map(|f| f.name.as_str()).collect();
+/// assert_eq!(names, ["mean", "quantile__q_0.1", "quantile__q_0.9"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeatureSettings {
+    features: BTreeMap<String, Vec<Params>>,
+}
+
+impl FeatureSettings {
+    /// Creates settings without any feature.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The features of tsfresh's `MinimalFCParameters`, the same as
+    /// [`MinimalFeatureSet`](crate::features::minimal::MinimalFeatureSet).
+    pub fn minimal() -> Self {
+        let mut settings = Self::new();
+        for feature in MINIMAL_FEATURES {
+            settings.insert(feature, vec![Params::new()]);
+        }
+        settings
+    }
+
+    /// All features of [`comprehensive`](Self::comprehensive) except the ones
+    /// with high computational cost, like tsfresh's `EfficientFCParameters`.
+    pub fn efficient() -> Self {
+        let mut settings = Self::comprehensive();
+        for feature in HIGH_COMP_COST_FEATURES {
+            settings.remove(feature);
+        }
+        settings
+    }
+
+    /// Every feature with tsfresh's default parameters, like
+    /// `ComprehensiveFCParameters`.
+    ///
+    /// `linear_trend_timewise`, which needs timestamps, and
+    /// `query_similarity_count`, which needs a user-supplied query, are not
+    /// included and have to be added explicitly. tsfresh's `cwt_coefficients`
+    /// is left out because only `ricker_cwt_coefficients`, which does not
+    /// reproduce its values, is implemented.
+    pub fn comprehensive() -> Self {
+        let mut settings = Self::minimal();
+        for feature in SIMPLE_FEATURES {
+            settings.insert(feature, vec![Params::new()]);
+        }
+
+        let lags = |range: std::ops::Range<i64>| -> Vec<Params> {
+            range.map(|lag| params([("lag", lag.into())])).collect()
+        };
+        let quantiles = [0.1, 0.2, 0.3, 0.4, 0.6, 0.7, 0.8, 0.9];
+        let single = |key: &str, values: Vec<Param>| -> Vec<Params> {
+            values
+                .into_iter()
+                .map(|value| params([(key, value)]))
+                .collect()
+        };
+
+        settings.insert("time_reversal_asymmetry_statistic", lags(1..4));
+        settings.insert("c3", lags(1..4));
+        settings.insert(
+            "cid_ce",
+            single("normalize", vec![true.into(), false.into()]),
+        );
+        settings.insert(
+            "symmetry_looking",
+            single("r", (0..20).map(|r| (f64::from(r) * 0.05).into()).collect()),
+        );
+        settings.insert(
+            "large_standard_deviation",
+            single("r", (1..20).map(|r| (f64::from(r) * 0.05).into()).collect()),
+        );
+        settings.insert(
+            "quantile",
+            single("q", quantiles.iter().map(|&q| q.into()).collect()),
+        );
+        settings.insert("autocorrelation", lags(0..10));
+        settings.insert(
+            "agg_autocorrelation",
+            ["mean", "median", "var"]
+                .into_iter()
+                .map(|f_agg| params([("f_agg", f_agg.into()), ("maxlag", 40.into())]))
+                .collect(),
+        );
+        settings.insert("partial_autocorrelation", lags(0..10));
+        settings.insert("number_cwt_peaks", single("n", vec![1.into(), 5.into()]));
+        settings.insert(
+            "number_peaks",
+            single("n", [1, 3, 5, 10, 50].map(Param::from).to_vec()),
+        );
+        settings.insert("binned_entropy", single("max_bins", vec![10.into()]));
+        settings.insert(
+            "index_mass_quantile",
+            single("q", quantiles.iter().map(|&q| q.into()).collect()),
+        );
+        settings.insert(
+            "spkt_welch_density",
+            single("coeff", [2, 5, 8].map(Param::from).to_vec()),
+        );
+        settings.insert(
+            "ar_coefficient",
+            (0..=10i64)
+                .map(|coeff| params([("coeff", coeff.into()), ("k", 10.into())]))
+                .collect(),
+        );
+        let mut change_quantiles = Vec::new();
+        for ql in [0.0, 0.2, 0.4, 0.6, 0.8] {
+            for qh in [0.2, 0.4, 0.6, 0.8, 1.0] {
+                for isabs in [false, true] {
+                    for f_agg in ["mean", "var"] {
+                        if ql < qh {
+                            change_quantiles.push(params([
+                                ("ql", ql.into()),
+                                ("qh", qh.into()),
+                                ("isabs", isabs.into()),
+                                ("f_agg", f_agg.into()),
+                            ]));
+                        }
+                    }
+                }
+            }
+        }
+        settings.insert("change_quantiles", change_quantiles);
+        settings.insert(
+            "fft_coefficient",
+            ["real", "imag", "abs", "angle"]
+                .into_iter()
+                .flat_map(|attr| {
+                    (0..100i64)
+                        .map(move |coeff| params([("coeff", coeff.into()), ("attr", attr.into())]))
+                })
+                .collect(),
+        );
+        settings.insert(
+            "fft_aggregated",
+            single(
+                "aggtype",
+                ["centroid", "variance", "skew", "kurtosis"]
+                    .map(Param::from)
+                    .to_vec(),
+            ),
+        );
+        settings.insert(
+            "value_count",
+            single("value", [0, 1, -1].map(Param::from).to_vec()),
+        );
+        settings.insert(
+            "range_count",
+            vec![
+                params([("min", (-1).into()), ("max", 1.into())]),
+                params([("min", (-1e12).into()), ("max", 0.into())]),
+                params([("min", 0.into()), ("max", 1e12.into())]),
+            ],
+        );
+        settings.insert(
+            "approximate_entropy",
+            [0.1, 0.3, 0.5, 0.7, 0.9]
+                .into_iter()
+                .map(|r| params([("m", 2.into()), ("r", r.into())]))
+                .collect(),
+        );
+        settings.insert(
+            "friedrich_coefficients",
+            (0..=3i64)
+                .map(|coeff| params([("coeff", coeff.into()), ("m", 3.into()), ("r", 30.into())]))
+                .collect(),
+        );
+        settings.insert(
+            "max_langevin_fixed_point",
+            vec![params([("m", 3.into()), ("r", 30.into())])],
+        );
+        settings.insert(
+            "linear_trend",
+            single(
+                "attr",
+                ["pvalue", "rvalue", "intercept", "slope", "stderr"]
+                    .map(Param::from)
+                    .to_vec(),
+            ),
+        );
+        let mut agg_linear_trend = Vec::new();
+        for attr in ["rvalue", "intercept", "slope", "stderr"] {
+            for chunk_len in [5, 10, 50] {
+                for f_agg in ["max", "min", "mean", "var"] {
+                    agg_linear_trend.push(params([
+                        ("attr", attr.into()),
+                        ("chunk_len", chunk_len.into()),
+                        ("f_agg", f_agg.into()),
+                    ]));
+                }
+            }
+        }
+        settings.insert("agg_linear_trend", agg_linear_trend);
+        settings.insert(
+            "augmented_dickey_fuller",
+            single(
+                "attr",
+                ["teststat", "pvalue", "usedlag"].map(Param::from).to_vec(),
+            ),
+        );
+        settings.insert(
+            "number_crossing_m",
+            single("m", [0, -1, 1].map(Param::from).to_vec()),
+        );
+        settings.insert(
+            "energy_ratio_by_chunks",
+            (0..10i64)
+                .map(|focus| params([("num_segments", 10.into()), ("segment_focus", focus.into())]))
+                .collect(),
+        );
+        settings.insert(
+            "ratio_beyond_r_sigma",
+            single(
+                "r",
+                vec![
+                    0.5.into(),
+                    1.into(),
+                    1.5.into(),
+                    2.into(),
+                    2.5.into(),
+                    3.into(),
+                    5.into(),
+                    6.into(),
+                    7.into(),
+                    10.into(),
+                ],
+            ),
+        );
+        settings.insert("count_above", single("t", vec![0.into()]));
+        settings.insert("count_below", single("t", vec![0.into()]));
+        let bins = || [2, 3, 5, 10, 100].map(Param::from).to_vec();
+        settings.insert("lempel_ziv_complexity", single("bins", bins()));
+        settings.insert("fourier_entropy", single("bins", bins()));
+        settings.insert(
+            "permutation_entropy",
+            (3..=7i64)
+                .map(|dimension| params([("tau", 1.into()), ("dimension", dimension.into())]))
+                .collect(),
+        );
+        settings.insert(
+            "matrix_profile",
+            ["min", "max", "mean", "median", "25", "75"]
+                .into_iter()
+                .map(|feature| params([("threshold", 0.98.into()), ("feature", feature.into())]))
+                .collect(),
+        );
+        // tsfresh's default dictionary repeats the key, so only the last value survives.
+        settings.insert(
+            "mean_n_absolute_max",
+            single("number_of_maxima", vec![7.into()]),
+        );
+        settings
+    }
+
+    /// Sets the parameter sets of a feature, returning the previous ones.
+    pub fn insert(
+        &mut self,
+        feature: impl Into<String>,
+        param_sets: Vec<Params>,
+    ) -> Option<Vec<Params>> {
+        self.features.insert(feature.into(), param_sets)
+    }
+
+    /// Removes a feature, returning its parameter sets.
+    pub fn remove(&mut self, feature: &str) -> Option<Vec<Params>> {
+        self.features.remove(feature)
+    }
+
+    /// Returns the parameter sets of a feature.
+    pub fn get(&self, feature: &str) -> Option<&[Params]> {
+        self.features.get(feature).map(Vec::as_slice)
+    }
+
+    /// Returns `true` if the feature is enabled.
+    pub fn contains(&self, feature: &str) -> bool {
+        self.features.contains_key(feature)
+    }
+
+    /// Iterates over the features and their parameter sets, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[Params])> {
+        self.features
+            .iter()
+            .map(|(feature, param_sets)| (feature.as_str(), param_sets.as_slice()))
+    }
+
+    /// Returns the number of enabled features.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns `true` if no feature is enabled.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Reconstructs the settings that compute the named features, like
+    /// tsfresh's `from_columns` for a single column.
+    ///
+    /// Every name is parsed with [`parse_feature_name`]. Building the returned
+    /// settings computes at least the named features; features that produce
+    /// several values per parameter set, such as `agg_autocorrelation`,
+    /// compute all of them. A `query_similarity_count` name only carries the
+    /// hash of its query, so the query has to be set again before building.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kalax::features::common::FeatureFunction;
+    /// use kalax::settings::FeatureSettings;
+    ///
+    /// let names = ["mean", "quantile__q_0.1", "fft_coefficient__attr_\"abs\"__coeff_2"];
+    /// let settings = FeatureSettings::from_names(names).unwrap();
+    /// let features = settings.build().unwrap().apply(&[1.0, 2.0, 3.0, 4.0]);
+    /// let mut extracted: Vec<&str> = features.iter().map(|f| f.name.as_str()).collect();
+    /// extracted.sort();
+    /// assert_eq!(extracted, ["fft_coefficient__attr_\"abs\"__coeff_2", "mean", "quantile__q_0.1"]);
+    /// ```
+    pub fn from_names<I>(names: I) -> Result<Self, SettingsError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut settings = Self::new();
+        for name in names {
+            let (feature, set) = parse_feature_name(name.as_ref())?;
+            let param_sets = settings.features.entry(feature.to_string()).or_default();
+            // Compare rendered values so that NaN parameters are deduplicated too.
+            let rendered = |set: &Params| -> Vec<(String, String)> {
+                set.iter()
+                    .map(|(key, value)| (key.clone(), value.format_param()))
+                    .collect()
+            };
+            if !param_sets
+                .iter()
+                .any(|existing| rendered(existing) == rendered(&set))
+            {
+                param_sets.push(set);
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Builds the feature functions for these settings.
+    ///
+    /// Parameter sets of features with a combining implementation, such as
+    /// `change_quantiles`, are passed to a single feature function so that
+    /// shared intermediate results are computed once per series.
+    pub fn build(&self) -> Result<FeatureSet, SettingsError> {
+        let mut functions = Vec::new();
+        for (feature, param_sets) in &self.features {
+            if !param_sets.is_empty() {
+                functions.extend(build_feature(feature, param_sets)?);
+            }
+        }
+        Ok(FeatureSet { functions })
+    }
+}
+
+/// A boxed feature function that can be shared across the rayon thread pool.
+pub type BoxedFeatureFunction = Box<dyn FeatureFunction + Send + Sync>;
+
+/// The feature functions built from [`FeatureSettings`].
+pub struct FeatureSet {
+    functions: Vec<BoxedFeatureFunction>,
+}
+
+impl FeatureFunction for FeatureSet {
+    fn apply(&self, series: &[f64]) -> Vec<FeatureFunctionReturn> {
+        self.functions
+            .iter()
+            .flat_map(|f| f.apply(series))
+            .collect()
+    }
+
+    fn apply_timed(&self, series: &TimeSeries<'_>) -> Vec<FeatureFunctionReturn> {
+        self.functions
+            .iter()
+            .flat_map(|f| f.apply_timed(series))
+            .collect()
+    }
+}
+
+/// Feature settings per column, the equivalent of tsfresh's
+/// `kind_to_fc_parameters`.
+///
+/// Columns without their own settings use the default settings.
+///
+/// # Examples
+///
+/// ```
+/// use kalax::settings::{ColumnSettings, FeatureSettings};
+///
+/// let mut settings = ColumnSettings::new(FeatureSettings::minimal());
+/// settings.insert("vibration", FeatureSettings::comprehensive());
+///
+/// assert_eq!(settings.get("vibration"), &FeatureSettings::comprehensive());
+/// assert_eq!(settings.get("temperature"), &FeatureSettings::minimal());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnSettings {
+    default: FeatureSettings,
+    columns: BTreeMap<String, FeatureSettings>,
+}
+
+impl ColumnSettings {
+    /// Creates per-column settings that use `default` for every column.
+    pub fn new(default: FeatureSettings) -> Self {
+        Self {
+            default,
+            columns: BTreeMap::new(),
+        }
+    }
+
+    /// Reconstructs per-column settings from names like
+    /// `vibration__fft_coefficient__attr_"abs"__coeff_2`, the equivalent of
+    /// tsfresh's `from_columns`.
+    ///
+    /// The part before the first `__` is the column name, the rest is parsed
+    /// by [`FeatureSettings::from_names`]. The default settings are empty, so
+    /// unlisted columns get no features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kalax::settings::{ColumnSettings, params};
+    ///
+    /// let names = ["temperature__mean", "vibration__quantile__q_0.9"];
+    /// let settings = ColumnSettings::from_columns(names).unwrap();
+    /// assert_eq!(settings.get("temperature").get("mean"), Some(&[params([])][..]));
+    /// assert!(settings.get("pressure").is_empty());
+    /// ```
+    pub fn from_columns<I>(names: I) -> Result<Self, SettingsError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut by_column: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for name in names {
+            let name = name.as_ref();
+            let (column, feature) = name
+                .split_once("__")
+                .filter(|(column, _)| !column.is_empty())
+                .ok_or_else(|| SettingsError::InvalidName(name.to_string()))?;
+            by_column
+                .entry(column.to_string())
+                .or_default()
+                .push(feature.to_string());
+        }
+        let mut settings = Self::default();
+        for (column, features) in by_column {
+            settings.insert(column, FeatureSettings::from_names(features)?);
+        }
+        Ok(settings)
+    }
+
+    /// Sets the settings of a column, returning the previous ones.
+    pub fn insert(
+        &mut self,
+        column: impl Into<String>,
+        settings: FeatureSettings,
+    ) -> Option<FeatureSettings> {
+        self.columns.insert(column.into(), settings)
+    }
+
+    /// Removes the settings of a column so that it uses the default again.
+    pub fn remove(&mut self, column: &str) -> Option<FeatureSettings> {
+        self.columns.remove(column)
+    }
+
+    /// Returns the settings used for a column.
+    pub fn get(&self, column: &str) -> &FeatureSettings {
+        self.columns.get(column).unwrap_or(&self.default)
+    }
+
+    /// Returns the settings used for columns without their own settings.
+    pub fn default_settings(&self) -> &FeatureSettings {
+        &self.default
+    }
+
+    /// Replaces the settings used for columns without their own settings.
+    pub fn set_default(&mut self, default: FeatureSettings) {
+        self.default = default;
+    }
+
+    /// Iterates over the columns with their own settings, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FeatureSettings)> {
+        self.columns
+            .iter()
+            .map(|(column, settings)| (column.as_str(), settings))
+    }
+
+    /// Builds the feature functions of the default and every listed column.
+    pub fn build(&self) -> Result<ColumnFeatureSets, SettingsError> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(column, settings)| Ok((column.clone(), settings.build()?)))
+            .collect::<Result<_, SettingsError>>()?;
+        Ok(ColumnFeatureSets {
+            default: self.default.build()?,
+            columns,
+        })
+    }
+}
+
+impl From<FeatureSettings> for ColumnSettings {
+    fn from(default: FeatureSettings) -> Self {
+        Self::new(default)
+    }
+}
+
+/// The feature functions built from [`ColumnSettings`].
+pub struct ColumnFeatureSets {
+    default: FeatureSet,
+    columns: HashMap<String, FeatureSet>,
+}
+
+impl ColumnFeatureSets {
+    /// Returns the feature functions used for a column.
+    pub fn get(&self, column: &str) -> &FeatureSet {
+        self.columns.get(column).unwrap_or(&self.default)
+    }
+}
+
+/// Reads typed parameters of one parameter set, reporting errors for `feature`.
+struct ParamReader<'a> {
+    feature: &'a str,
+    params: &'a Params,
+}
+
+impl<'a> ParamReader<'a> {
+    /// Creates a reader after checking that only `allowed` keys are present.
+    fn new(feature: &'a str, params: &'a Params, allowed: &[&str]) -> Result<Self, SettingsError> {
+        if let Some(key) = params.keys().find(|key| !allowed.contains(&key.as_str())) {
+            return Err(SettingsError::UnexpectedParameter {
+                feature: feature.to_string(),
+                key: key.clone(),
+            });
+        }
+        Ok(Self { feature, params })
+    }
+
+    fn optional(&self, key: &str) -> Option<&'a Param> {
+        self.params.get(key)
+    }
+
+    fn required(&self, key: &str) -> Result<&'a Param, SettingsError> {
+        self.optional(key)
+            .ok_or_else(|| SettingsError::MissingParameter {
+                feature: self.feature.to_string(),
+                key: key.to_string(),
+            })
+    }
+
+    fn invalid(&self, key: &str, value: &Param) -> SettingsError {
+        SettingsError::InvalidParameter {
+            feature: self.feature.to_string(),
+            key: key.to_string(),
+            value: value.clone(),
+        }
+    }
+
+    fn usize(&self, key: &str) -> Result<usize, SettingsError> {
+        let value = self.required(key)?;
+        match value {
+            Param::Int(v) => usize::try_from(*v).map_err(|_| self.invalid(key, value)),
+            _ => Err(self.invalid(key, value)),
+        }
+    }
+
+    /// Reads an optional integer, treating `None` like a missing value.
+    fn optional_usize(&self, key: &str) -> Result<Option<usize>, SettingsError> {
+        match self.optional(key) {
+            None | Some(Param::None) => Ok(None),
+            Some(_) => self.usize(key).map(Some),
+        }
+    }
+
+    /// Reads a number in `[0, 1]`, such as a quantile or a correlation threshold.
+    fn unit_interval(&self, key: &str) -> Result<Number, SettingsError> {
+        let value = self.number(key)?;
+        if !is_unit_interval(value.as_f64()) {
+            return Err(self.invalid(key, self.required(key)?));
+        }
+        Ok(value)
+    }
+
+    fn number(&self, key: &str) -> Result<Number, SettingsError> {
+        let value = self.required(key)?;
+        match value {
+            Param::Int(v) => Ok(Number::Int(*v)),
+            Param::Float(v) => Ok(Number::Float(*v)),
+            _ => Err(self.invalid(key, value)),
+        }
+    }
+
+    fn bool(&self, key: &str) -> Result<bool, SettingsError> {
+        let value = self.required(key)?;
+        match value {
+            Param::Bool(v) => Ok(*v),
+            _ => Err(self.invalid(key, value)),
+        }
+    }
+
+    fn tuple(&self, key: &str) -> Result<Vec<usize>, SettingsError> {
+        let value = self.required(key)?;
+        match value {
+            Param::Tuple(v) => Ok(v.clone()),
+            _ => Err(self.invalid(key, value)),
+        }
+    }
+
+    fn series(&self, key: &str) -> Result<Vec<f64>, SettingsError> {
+        let value = self.required(key)?;
+        match value {
+            Param::Series(v) => Ok(v.clone()),
+            _ => Err(self.invalid(key, value)),
+        }
+    }
+
+    /// Reads a string parameter naming one of `variants`.
+    fn choice<T: Copy>(
+        &self,
+        key: &str,
+        variants: &[T],
+        as_str: fn(&T) -> &'static str,
+    ) -> Result<T, SettingsError> {
+        let value = self.required(key)?;
+        match value {
+            Param::Str(s) => variants
+                .iter()
+                .find(|variant| as_str(variant) == s)
+                .copied()
+                .ok_or_else(|| self.invalid(key, value)),
+            _ => Err(self.invalid(key, value)),
+        }
+    }
+
+    fn aggregation(&self, key: &str) -> Result<Aggregation, SettingsError> {
+        self.choice(key, &AGGREGATIONS, Aggregation::as_str)
+    }
+
+    fn trend_attribute(&self, key: &str) -> Result<TrendAttribute, SettingsError> {
+        self.choice(key, &TREND_ATTRIBUTES, TrendAttribute::as_str)
+    }
+}
+
+const AGGREGATIONS: [Aggregation; 6] = [
+    Aggregation::Mean,
+    Aggregation::Median,
+    Aggregation::Var,
+    Aggregation::Std,
+    Aggregation::Min,
+    Aggregation::Max,
+];
+
+const TREND_ATTRIBUTES: [TrendAttribute; 5] = [
+    TrendAttribute::PValue,
+    TrendAttribute::RValue,
+    TrendAttribute::Intercept,
+    TrendAttribute::Slope,
+    TrendAttribute::StdErr,
+];
+
+/// Reads every parameter set of `feature` with `read`, which only sees the
+/// `allowed` keys.
+fn read_all<T>(
+    feature: &str,
+    param_sets: &[Params],
+    allowed: &[&str],
+    read: impl Fn(&ParamReader<'_>) -> Result<T, SettingsError>,
+) -> Result<Vec<T>, SettingsError> {
+    param_sets
+        .iter()
+        .map(|params| read(&ParamReader::new(feature, params, allowed)?))
+        .collect()
+}
+
+/// Boxes a feature function.
+fn boxed(function: impl FeatureFunction + Send + Sync + 'static) -> BoxedFeatureFunction {
+    Box::new(function)
+}
+
+/// Builds the feature functions computing `feature` for all `param_sets`.
+fn build_feature(
+    feature: &str,
+    param_sets: &[Params],
+) -> Result<Vec<BoxedFeatureFunction>, SettingsError> {
+    // One function per parameter set, for features without a combiner.
+    let each =
+        |allowed: &[&str],
+         build: &dyn Fn(&ParamReader<'_>) -> Result<BoxedFeatureFunction, SettingsError>| {
+            read_all(feature, param_sets, allowed, build)
+        };
+    // Parameterless features are computed once, however many empty sets are given.
+    let simple =
+        |function: BoxedFeatureFunction| -> Result<Vec<BoxedFeatureFunction>, SettingsError> {
+            read_all(feature, param_sets, &[], |_| Ok(()))?;
+            Ok(vec![function])
+        };
+
+    let functions = match feature {
+        "abs_energy" => simple(boxed(AbsEnergy::new()))?,
+        "absolute_maximum" => simple(boxed(AbsoluteMaximum::new()))?,
+        "absolute_sum_of_changes" => simple(boxed(AbsoluteSumOfChanges::new()))?,
+        "benford_correlation" => simple(boxed(BenfordCorrelation::new()))?,
+        "count_above_mean" => simple(boxed(CountAboveMean::new()))?,
+        "count_below_mean" => simple(boxed(CountBelowMean::new()))?,
+        "first_location_of_maximum" => simple(boxed(FirstLocationOfMaximum::new()))?,
+        "first_location_of_minimum" => simple(boxed(FirstLocationOfMinimum::new()))?,
+        "has_duplicate" => simple(boxed(HasDuplicate::new()))?,
+        "has_duplicate_max" => simple(boxed(HasDuplicateMax::new()))?,
+        "has_duplicate_min" => simple(boxed(HasDuplicateMin::new()))?,
+        "kurtosis" => simple(boxed(Kurtosis::new()))?,
+        "last_location_of_maximum" => simple(boxed(LastLocationOfMaximum::new()))?,
+        "last_location_of_minimum" => simple(boxed(LastLocationOfMinimum::new()))?,
+        "length" => simple(boxed(Length::new()))?,
+        "longest_strike_above_mean" => simple(boxed(LongestStrikeAboveMean::new()))?,
+        "longest_strike_below_mean" => simple(boxed(LongestStrikeBelowMean::new()))?,
+        "maximum" => simple(boxed(Maximum::new()))?,
+        "mean" => simple(boxed(Mean::new()))?,
+        "mean_abs_change" => simple(boxed(MeanAbsChange::new()))?,
+        "mean_change" => simple(boxed(MeanChange::new()))?,
+        "mean_second_derivative_central" => simple(boxed(MeanSecondDerivativeCentral::new()))?,
+        "median" => simple(boxed(Median::new()))?,
+        "minimum" => simple(boxed(Minimum::new()))?,
+        "percentage_of_reoccurring_datapoints_to_all_datapoints" => simple(boxed(
+            PercentageOfReoccurringDatapointsToAllDatapoints::new(),
+        ))?,
+        "percentage_of_reoccurring_values_to_all_values" => {
+            simple(boxed(PercentageOfReoccurringValuesToAllValues::new()))?
+        }
+        "ratio_value_number_to_time_series_length" => {
+            simple(boxed(RatioValueNumberToTimeSeriesLength::new()))?
+        }
+        "root_mean_square" => simple(boxed(RootMeanSquare::new()))?,
+        "sample_entropy" => simple(boxed(SampleEntropy::new()))?,
+        "skewness" => simple(boxed(Skewness::new()))?,
+        "standard_deviation" => simple(boxed(StandardDeviation::new()))?,
+        "sum_of_reoccurring_data_points" => simple(boxed(SumOfReoccurringDataPoints::new()))?,
+        "sum_of_reoccurring_values" => simple(boxed(SumOfReoccurringValues::new()))?,
+        "sum_values" => simple(boxed(SumValues::new()))?,
+        "variance" => simple(boxed(Variance::new()))?,
+        "variance_larger_than_standard_deviation" => {
+            simple(boxed(VarianceLargerThanStandardDeviation::new()))?
+        }
+        "variation_coefficient" => simple(boxed(VariationCoefficient::new()))?,
+
+        "approximate_entropy" => each(&["m", "r"], &|p| {
+            Ok(boxed(ApproximateEntropy::new(
+                p.usize("m")?,
+                p.number("r")?,
+            )))
+        })?,
+        "autocorrelation" => each(&["lag"], &|p| {
+            Ok(boxed(Autocorrelation::new(p.usize("lag")?)))
+        })?,
+        "binned_entropy" => each(&["max_bins"], &|p| {
+            Ok(boxed(BinnedEntropy::new(p.usize("max_bins")?)))
+        })?,
+        "c3" => each(&["lag"], &|p| Ok(boxed(C3::new(p.usize("lag")?))))?,
+        "cid_ce" => each(&["normalize"], &|p| {
+            Ok(boxed(CidCe::new(p.bool("normalize")?)))
+        })?,
+        "count_above" => each(&["t"], &|p| Ok(boxed(CountAbove::new(p.number("t")?))))?,
+        "count_below" => each(&["t"], &|p| Ok(boxed(CountBelow::new(p.number("t")?))))?,
+        "fourier_entropy" => each(&["bins"], &|p| {
+            Ok(boxed(FourierEntropy::new(p.usize("bins")?)))
+        })?,
+        "large_standard_deviation" => each(&["r"], &|p| {
+            Ok(boxed(LargeStandardDeviation::new(p.number("r")?)))
+        })?,
+        "lempel_ziv_complexity" => each(&["bins"], &|p| {
+            Ok(boxed(LempelZivComplexity::new(p.usize("bins")?)))
+        })?,
+        "max_langevin_fixed_point" => each(&["m", "r"], &|p| {
+            Ok(boxed(MaxLangevinFixedPoint::new(
+                p.usize("m")?,
+                p.usize("r")?,
+            )))
+        })?,
+        "mean_n_absolute_max" => each(&["number_of_maxima"], &|p| {
+            Ok(boxed(MeanNAbsoluteMax::new(p.usize("number_of_maxima")?)))
+        })?,
+        "number_crossing_m" => each(&["m"], &|p| Ok(boxed(NumberCrossingM::new(p.number("m")?))))?,
+        "number_cwt_peaks" => each(&["n"], &|p| Ok(boxed(NumberCwtPeaks::new(p.usize("n")?))))?,
+        "number_peaks" => each(&["n"], &|p| Ok(boxed(NumberPeaks::new(p.usize("n")?))))?,
+        "permutation_entropy" => each(&["tau", "dimension"], &|p| {
+            Ok(boxed(PermutationEntropy::new(
+                p.usize("tau")?,
+                p.usize("dimension")?,
+            )))
+        })?,
+        "quantile" => each(&["q"], &|p| Ok(boxed(Quantile::new(p.unit_interval("q")?))))?,
+        "range_count" => each(&["min", "max"], &|p| {
+            Ok(boxed(RangeCount::new(p.number("min")?, p.number("max")?)))
+        })?,
+        "ratio_beyond_r_sigma" => each(&["r"], &|p| {
+            Ok(boxed(RatioBeyondRSigma::new(p.number("r")?)))
+        })?,
+        "time_reversal_asymmetry_statistic" => each(&["lag"], &|p| {
+            Ok(boxed(TimeReversalAsymmetryStatistic::new(p.usize("lag")?)))
+        })?,
+        "value_count" => each(&["value"], &|p| {
+            Ok(boxed(ValueCount::new(p.number("value")?)))
+        })?,
+
+        "agg_autocorrelation" => vec![boxed(AggAutocorrelation::new(read_all(
+            feature,
+            param_sets,
+            &["f_agg", "maxlag"],
+            |p| Ok((p.aggregation("f_agg")?, p.usize("maxlag")?)),
+        )?))],
+        "agg_linear_trend" => vec![boxed(AggLinearTrend::new(read_all(
+            feature,
+            param_sets,
+            &["attr", "chunk_len", "f_agg"],
+            |p| {
+                Ok((
+                    p.trend_attribute("attr")?,
+                    p.usize("chunk_len")?,
+                    p.aggregation("f_agg")?,
+                ))
+            },
+        )?))],
+        "ar_coefficient" => {
+            let fits = read_all(feature, param_sets, &["coeff", "k", "method"], |p| {
+                let estimator = match p.optional("method") {
+                    None => ArEstimator::default(),
+                    Some(_) => p.choice(
+                        "method",
+                        &[ArEstimator::Ols, ArEstimator::YuleWalker, ArEstimator::Burg],
+                        ArEstimator::as_str,
+                    )?,
+                };
+                Ok((estimator, (p.usize("coeff")?, p.usize("k")?)))
+            })?;
+            // One function per estimator, each sharing its fits across coefficients.
+            let mut by_estimator: Vec<(ArEstimator, Vec<(usize, usize)>)> = Vec::new();
+            for (estimator, param) in fits {
+                match by_estimator.iter_mut().find(|(e, _)| *e == estimator) {
+                    Some((_, params)) => params.push(param),
+                    None => by_estimator.push((estimator, vec![param])),
+                }
+            }
+            by_estimator
+                .into_iter()
+                .map(|(estimator, params)| boxed(ArCoefficient::new(params, estimator)))
+                .collect()
+        }
+        "augmented_dickey_fuller" => vec![boxed(AugmentedDickeyFuller::new(read_all(
+            feature,
+            param_sets,
+            &["attr", "autolag"],
+            |p| {
+                let attr = p.choice(
+                    "attr",
+                    &[
+                        AdfAttribute::TestStat,
+                        AdfAttribute::PValue,
+                        AdfAttribute::UsedLag,
+                    ],
+                    AdfAttribute::as_str,
+                )?;
+                let autolag = match p.optional("autolag") {
+                    None => Some(Autolag::Aic),
+                    Some(Param::None) => None,
+                    Some(Param::Str(s)) if s == "None" => None,
+                    Some(_) => Some(p.choice(
+                        "autolag",
+                        &[Autolag::Aic, Autolag::Bic, Autolag::TStat],
+                        Autolag::as_str,
+                    )?),
+                };
+                Ok((attr, autolag))
+            },
+        )?))],
+        "change_quantiles" => vec![boxed(ChangeQuantiles::new(read_all(
+            feature,
+            param_sets,
+            &["ql", "qh", "isabs", "f_agg"],
+            |p| {
+                Ok((
+                    p.unit_interval("ql")?,
+                    p.unit_interval("qh")?,
+                    p.bool("isabs")?,
+                    p.aggregation("f_agg")?,
+                ))
+            },
+        )?))],
+        "ricker_cwt_coefficients" => vec![boxed(RickerCwtCoefficients::new(read_all(
+            feature,
+            param_sets,
+            &["widths", "coeff", "w"],
+            |p| Ok((p.tuple("widths")?, p.usize("coeff")?, p.usize("w")?)),
+        )?))],
+        "energy_ratio_by_chunks" => vec![boxed(EnergyRatioByChunks::new(read_all(
+            feature,
+            param_sets,
+            &["num_segments", "segment_focus"],
+            |p| Ok((p.usize("num_segments")?, p.usize("segment_focus")?)),
+        )?))],
+        "fft_aggregated" => vec![boxed(FftAggregated::new(read_all(
+            feature,
+            param_sets,
+            &["aggtype"],
+            |p| {
+                p.choice(
+                    "aggtype",
+                    &[
+                        FftAggType::Centroid,
+                        FftAggType::Variance,
+                        FftAggType::Skew,
+                        FftAggType::Kurtosis,
+                    ],
+                    FftAggType::as_str,
+                )
+            },
+        )?))],
+        "fft_coefficient" => vec![boxed(FftCoefficient::new(read_all(
+            feature,
+            param_sets,
+            &["coeff", "attr"],
+            |p| {
+                let attr = p.choice(
+                    "attr",
+                    &[
+                        FftAttribute::Real,
+                        FftAttribute::Imag,
+                        FftAttribute::Abs,
+                        FftAttribute::Angle,
+                    ],
+                    FftAttribute::as_str,
+                )?;
+                Ok((p.usize("coeff")?, attr))
+            },
+        )?))],
+        "friedrich_coefficients" => vec![boxed(FriedrichCoefficients::new(read_all(
+            feature,
+            param_sets,
+            &["coeff", "m", "r"],
+            |p| Ok((p.usize("coeff")?, p.usize("m")?, p.usize("r")?)),
+        )?))],
+        "index_mass_quantile" => vec![boxed(IndexMassQuantile::new(read_all(
+            feature,
+            param_sets,
+            &["q"],
+            |p| p.unit_interval("q"),
+        )?))],
+        "linear_trend" => vec![boxed(LinearTrend::new(read_all(
+            feature,
+            param_sets,
+            &["attr"],
+            |p| p.trend_attribute("attr"),
+        )?))],
+        "linear_trend_timewise" => vec![boxed(LinearTrendTimewise::new(read_all(
+            feature,
+            param_sets,
+            &["attr"],
+            |p| p.trend_attribute("attr"),
+        )?))],
+        "matrix_profile" => vec![boxed(MatrixProfile::new(read_all(
+            feature,
+            param_sets,
+            &["windows", "feature", "threshold"],
+            |p| {
+                let statistic = p.choice(
+                    "feature",
+                    &[
+                        MatrixProfileStatistic::Min,
+                        MatrixProfileStatistic::Max,
+                        MatrixProfileStatistic::Mean,
+                        MatrixProfileStatistic::Median,
+                        MatrixProfileStatistic::Percentile25,
+                        MatrixProfileStatistic::Percentile75,
+                    ],
+                    MatrixProfileStatistic::as_str,
+                )?;
+                let threshold = match p.optional("threshold") {
+                    None => None,
+                    Some(_) => Some(p.unit_interval("threshold")?),
+                };
+                Ok((p.optional_usize("windows")?, statistic, threshold))
+            },
+        )?))],
+        "partial_autocorrelation" => vec![boxed(PartialAutocorrelation::new(read_all(
+            feature,
+            param_sets,
+            &["lag"],
+            |p| p.usize("lag"),
+        )?))],
+        "query_similarity_count" => vec![boxed(QuerySimilarityCount::new(read_all(
+            feature,
+            param_sets,
+            &["query", "threshold", "normalize"],
+            |p| {
+                let threshold = match p.optional("threshold") {
+                    None => None,
+                    Some(_) => Some(p.number("threshold")?),
+                };
+                let normalize = match p.optional("normalize") {
+                    None => None,
+                    Some(_) => Some(p.bool("normalize")?),
+                };
+                Ok((p.series("query")?, threshold, normalize))
+            },
+        )?))],
+        "spkt_welch_density" => {
+            let densities = read_all(
+                feature,
+                param_sets,
+                &["coeff", "segment_length", "overlap", "window"],
+                |p| {
+                    let default = WelchConfig::DEFAULT;
+                    let window = match p.optional("window") {
+                        None => default.window,
+                        Some(_) => p.choice(
+                            "window",
+                            &[
+                                WelchWindow::Hann,
+                                WelchWindow::Hamming,
+                                WelchWindow::Blackman,
+                            ],
+                            WelchWindow::as_str,
+                        )?,
+                    };
+                    let config = WelchConfig {
+                        segment_length: p
+                            .optional_usize("segment_length")?
+                            .unwrap_or(default.segment_length),
+                        overlap: p.optional_usize("overlap")?,
+                        window,
+                    };
+                    Ok((config, p.usize("coeff")?))
+                },
+            )?;
+            // One function per Welch configuration, each sharing its density.
+            let mut by_config: Vec<(WelchConfig, Vec<usize>)> = Vec::new();
+            for (config, coeff) in densities {
+                match by_config.iter_mut().find(|(c, _)| *c == config) {
+                    Some((_, coeffs)) => coeffs.push(coeff),
+                    None => by_config.push((config, vec![coeff])),
+                }
+            }
+            by_config
+                .into_iter()
+                .map(|(config, coeffs)| boxed(SpktWelchDensity::new(coeffs, config)))
+                .collect()
+        }
+        "symmetry_looking" => vec![boxed(SymmetryLooking::new(read_all(
+            feature,
+            param_sets,
+            &["r"],
+            |p| p.number("r"),
+        )?))],
+        _ => return Err(SettingsError::UnknownFeature(feature.to_string())),
+    };
+    Ok(functions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use super::*;
+    use crate::{
+        features::{common::Timestamps, minimal::MinimalFeatureSet},
+        test_utils::synthetic_series,
+    };
+
+    /// Feature names and flags listed in the feature plan.
+    struct PlannedFeature {
+        name: String,
+        minimal: bool,
+        high_comp_cost: bool,
+    }
+
+    fn planned_features() -> Vec<PlannedFeature> {
+        let contents =
+            std::fs::read_to_string("../plan/tsfresh.yaml").expect("failed to read feature plan");
+        let mut features: Vec<PlannedFeature> = Vec::new();
+        for line in contents.lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("- name: ") {
+                features.push(PlannedFeature {
+                    name: name.to_string(),
+                    minimal: false,
+                    high_comp_cost: false,
+                });
+            } else if let Some(feature) = features.last_mut() {
+                match line {
+                    "minimal: true" => feature.minimal = true,
+                    "high_comp_cost: true" => feature.high_comp_cost = true,
+                    _ => {}
+                }
+            }
+        }
+        features
+    }
+
+    fn names(settings: &FeatureSettings) -> BTreeSet<String> {
+        settings.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_presets_mirror_plan() {
+        let planned = planned_features();
+        let minimal: BTreeSet<String> = planned
+            .iter()
+            .filter(|f| f.minimal)
+            .map(|f| f.name.clone())
+            .collect();
+        assert_eq!(names(&FeatureSettings::minimal()), minimal);
+
+        let excluded = [
+            "linear_trend_timewise",
+            "query_similarity_count",
+            "cwt_coefficients",
+        ];
+        let comprehensive: BTreeSet<String> = planned
+            .iter()
+            .map(|f| f.name.clone())
+            .filter(|name| !excluded.contains(&name.as_str()))
+            .collect();
+        assert_eq!(names(&FeatureSettings::comprehensive()), comprehensive);
+
+        let efficient: BTreeSet<String> = planned
+            .iter()
+            .filter(|f| !f.high_comp_cost && !excluded.contains(&f.name.as_str()))
+            .map(|f| f.name.clone())
+            .collect();
+        assert_eq!(names(&FeatureSettings::efficient()), efficient);
+    }
+
+    #[test]
+    fn test_minimal_matches_minimal_feature_set() {
+        let series = synthetic_series(120);
+        let expected: HashMap<String, f64> = MinimalFeatureSet::new()
+            .apply(&series)
+            .into_iter()
+            .map(|f| (f.name, f.value))
+            .collect();
+        let result: HashMap<String, f64> = FeatureSettings::minimal()
+            .build()
+            .unwrap()
+            .apply(&series)
+            .into_iter()
+            .map(|f| (f.name, f.value))
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_comprehensive_yields_one_value_per_parameter_set() {
+        let settings = FeatureSettings::comprehensive();
+        let results = settings.build().unwrap().apply(&synthetic_series(120));
+        let expected: usize = settings.iter().map(|(_, sets)| sets.len()).sum();
+        assert_eq!(results.len(), expected);
+
+        let unique: BTreeSet<&str> = results.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(unique.len(), results.len());
+        for (feature, _) in settings.iter() {
+            assert!(
+                results
+                    .iter()
+                    .any(|f| f.name == feature || f.name.starts_with(&format!("{feature}__"))),
+                "no values for {feature}"
+            );
+        }
+        for name in [
+            "symmetry_looking__r_0.15000000000000002",
+            "ratio_beyond_r_sigma__r_2",
+            "ratio_beyond_r_sigma__r_2.5",
+            "range_count__max_0__min_-1000000000000.0",
+            "fft_coefficient__attr_\"angle\"__coeff_99",
+            "agg_autocorrelation__f_agg_\"var\"__maxlag_40",
+            "mean_n_absolute_max__number_of_maxima_7",
+        ] {
+            assert!(unique.contains(name), "missing {name}");
+        }
+    }
+
+    #[test]
+    fn test_parameter_sets() {
+        let series = synthetic_series(120);
+        let mut settings = FeatureSettings::new();
+        settings.insert(
+            "ar_coefficient",
+            vec![
+                params([("coeff", 1.into()), ("k", 3.into())]),
+                params([
+                    ("coeff", 1.into()),
+                    ("k", 3.into()),
+                    ("method", "burg".into()),
+                ]),
+            ],
+        );
+        settings.insert(
+            "augmented_dickey_fuller",
+            vec![
+                params([("attr", "usedlag".into())]),
+                params([("attr", "usedlag".into()), ("autolag", Param::None)]),
+            ],
+        );
+        settings.insert(
+            "query_similarity_count",
+            vec![params([("query", series[..10].to_vec().into())])],
+        );
+        settings.insert("mean", vec![params([]), params([])]);
+        settings.insert("median", vec![]);
+        let results = settings.build().unwrap().apply(&series);
+        let names: Vec<&str> = results.iter().map(|f| f.name.as_str()).collect();
+        let query = query_hash(&series[..10]);
+        assert_eq!(
+            names,
+            [
+                "ar_coefficient__coeff_1__k_3",
+                "ar_coefficient__coeff_1__k_3__method_\"burg\"",
+                "augmented_dickey_fuller__attr_\"usedlag\"__autolag_\"AIC\"",
+                "augmented_dickey_fuller__attr_\"usedlag\"__autolag_\"None\"",
+                "mean",
+                &format!("query_similarity_count__query_\"{query}\""),
+            ]
+        );
+        assert_eq!(results[5].value, 1.0);
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        let series = synthetic_series(120);
+        let seconds: Vec<f64> = (0..series.len()).map(|i| 60.0 * i as f64).collect();
+        let timed = TimeSeries::new(&series).with_timestamps(Timestamps::Seconds(&seconds));
+        let mut settings = FeatureSettings::comprehensive();
+        settings.insert(
+            "linear_trend_timewise",
+            vec![params([("attr", "slope".into())])],
+        );
+        settings.insert(
+            "matrix_profile",
+            vec![params([("feature", "25".into()), ("windows", 8.into())])],
+        );
+        settings.insert(
+            "spkt_welch_density",
+            vec![params([
+                ("coeff", 1.into()),
+                ("segment_length", 32.into()),
+                ("window", "hamming".into()),
+            ])],
+        );
+        settings.insert(
+            "augmented_dickey_fuller",
+            vec![params([
+                ("attr", "pvalue".into()),
+                ("autolag", Param::None),
+            ])],
+        );
+        settings.insert(
+            "value_count",
+            vec![
+                params([("value", f64::NAN.into())]),
+                params([("value", 1e-5.into())]),
+            ],
+        );
+        settings.insert(
+            "count_above",
+            vec![params([("t", f64::NEG_INFINITY.into())])],
+        );
+
+        let expected = settings.build().unwrap().apply_timed(&timed);
+        let names: Vec<&str> = expected.iter().map(|f| f.name.as_str()).collect();
+        let parsed = FeatureSettings::from_names(&names).unwrap();
+        let results = parsed.build().unwrap().apply_timed(&timed);
+        assert_eq!(results.len(), expected.len());
+        let values: HashMap<&str, f64> =
+            results.iter().map(|f| (f.name.as_str(), f.value)).collect();
+        for feature in &expected {
+            let value = values[feature.name.as_str()];
+            assert!(
+                value == feature.value || (value.is_nan() && feature.value.is_nan()),
+                "{} differs",
+                feature.name
+            );
+        }
+
+        let query = series[..10].to_vec();
+        let name = format!(
+            "query_similarity_count__normalize_True__query_\"{}\"__threshold_0.0",
+            query_hash(&query)
+        );
+        let mut parsed = FeatureSettings::from_names([&name]).unwrap();
+        assert!(matches!(
+            parsed.build().err(),
+            Some(SettingsError::InvalidParameter { key, .. }) if key == "query"
+        ));
+        let mut set = parsed.get("query_similarity_count").unwrap()[0].clone();
+        set.insert("query".to_string(), query.into());
+        parsed.insert("query_similarity_count", vec![set]);
+        assert_eq!(parsed.build().unwrap().apply(&series)[0].name, name);
+
+        // Parameters with defaults only appear in names that set them.
+        let names = [
+            "matrix_profile__feature_\"min\"__windows_8",
+            "matrix_profile__feature_\"max\"__threshold_0.9",
+        ];
+        let parsed = FeatureSettings::from_names(names).unwrap();
+        let results = parsed.build().unwrap().apply(&series);
+        let extracted: Vec<&str> = results.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(extracted, names);
+    }
+
+    #[test]
+    fn test_integer_names_round_trip() {
+        let names = [
+            "approximate_entropy__m_2__r_1",
+            "change_quantiles__f_agg_\"mean\"__isabs_False__qh_1__ql_0",
+            "index_mass_quantile__q_1",
+            "large_standard_deviation__r_1",
+            "quantile__q_0",
+            "quantile__q_1",
+            "symmetry_looking__r_0",
+        ];
+        let settings = FeatureSettings::from_names(names).unwrap();
+        let results = settings.build().unwrap().apply(&synthetic_series(60));
+        let mut extracted: Vec<&str> = results.iter().map(|f| f.name.as_str()).collect();
+        extracted.sort_unstable();
+        assert_eq!(extracted, names);
+    }
+
+    #[test]
+    fn test_from_columns() {
+        let settings = ColumnSettings::from_columns([
+            "vibration__fft_coefficient__attr_\"abs\"__coeff_2",
+            "vibration__ricker_cwt_coefficients__coeff_0__w_2__widths_(2,)",
+            "temperature__mean",
+            "temperature__mean",
+        ])
+        .unwrap();
+        let columns: Vec<(&str, &FeatureSettings)> = settings.iter().collect();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(
+            settings.get("vibration").get("ricker_cwt_coefficients"),
+            Some(
+                &[params([
+                    ("coeff", 0.into()),
+                    ("w", 2.into()),
+                    ("widths", vec![2].into()),
+                ])][..]
+            )
+        );
+        assert_eq!(
+            settings.get("temperature").get("mean"),
+            Some(&[params([])][..])
+        );
+        assert!(settings.default_settings().is_empty());
+
+        for name in ["mean", "__mean"] {
+            assert_eq!(
+                ColumnSettings::from_columns([name]).err(),
+                Some(SettingsError::InvalidName(name.to_string()))
+            );
+        }
+        for name in [
+            "quantile__q",
+            "quantile__q_",
+            "quantile__q_abc",
+            "quantile__q_0.1__q_0.2",
+            "fft_coefficient__attr_\"abs",
+            "cwt_coefficients__widths_(2, x)",
+            "quantile__q_infinity",
+        ] {
+            assert_eq!(
+                parse_feature_name(name).err(),
+                Some(SettingsError::InvalidName(name.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        let build = |feature: &str, sets: Vec<Params>| {
+            let mut settings = FeatureSettings::new();
+            settings.insert(feature, sets);
+            settings.build().err()
+        };
+        assert_eq!(
+            build("quantiles", vec![params([])]),
+            Some(SettingsError::UnknownFeature("quantiles".to_string()))
+        );
+        // The Ricker transform does not reproduce tsfresh's values, so
+        // tsfresh's name is not accepted for it.
+        assert_eq!(
+            build("cwt_coefficients", vec![params([])]),
+            Some(SettingsError::UnknownFeature(
+                "cwt_coefficients".to_string()
+            ))
+        );
+        assert_eq!(
+            build("quantile", vec![params([])]),
+            Some(SettingsError::MissingParameter {
+                feature: "quantile".to_string(),
+                key: "q".to_string(),
+            })
+        );
+        assert_eq!(
+            build("mean", vec![params([("q", 0.5.into())])]),
+            Some(SettingsError::UnexpectedParameter {
+                feature: "mean".to_string(),
+                key: "q".to_string(),
+            })
+        );
+        assert_eq!(
+            build("number_peaks", vec![params([("n", (-1).into())])]),
+            Some(SettingsError::InvalidParameter {
+                feature: "number_peaks".to_string(),
+                key: "n".to_string(),
+                value: Param::Int(-1),
+            })
+        );
+        for (feature, set) in [
+            ("quantile", params([("q", 1.5.into())])),
+            ("index_mass_quantile", params([("q", (-1).into())])),
+            (
+                "change_quantiles",
+                params([
+                    ("ql", 0.2.into()),
+                    ("qh", 2.into()),
+                    ("isabs", false.into()),
+                    ("f_agg", "mean".into()),
+                ]),
+            ),
+            (
+                "matrix_profile",
+                params([("feature", "min".into()), ("threshold", f64::NAN.into())]),
+            ),
+        ] {
+            assert!(
+                matches!(
+                    build(feature, vec![set]),
+                    Some(SettingsError::InvalidParameter { .. })
+                ),
+                "{feature} accepted an out-of-range value"
+            );
+        }
+        let error = build("linear_trend", vec![params([("attr", "slop".into())])]).unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid value \"slop\" for parameter `attr` of feature `linear_trend`"
+        );
+    }
+}
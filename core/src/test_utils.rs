@@ -18,3 +18,12 @@ macro_rules! assert_float_eq {
         );
     };
 }
+
+/// Deterministic test series of length `n`: a sine, a chirp-like cosine and a
+/// slight upward trend, irregular enough to exercise most features.
+pub fn synthetic_series(n: usize) -> Vec<f64> {
+    std::iter::successors(Some(0.0), |t| Some(t + 1.0))
+        .take(n)
+        .map(|t: f64| (0.3 * t).sin() + 0.5 * (1.1 * t * t).cos() + 0.01 * t)
+        .collect()
+}